@@ -1,3 +1,4 @@
+use num_bigint::BigUint;
 use serde::Serialize;
 use std::net::{Ipv4Addr, Ipv6Addr};
 use std::str::FromStr;
@@ -10,6 +11,10 @@ pub enum IpTranslationType {
     Ipv6Mask,
     Ipv4Range,
     Ipv6Range,
+    /// 两端都是同族合法地址但起始大于结束；translate_ip 不会自动交换，而是报“范围是降序的”
+    DescendingRange,
+    /// "起始地址+数量" 写法，如 10.0.0.0+256；translate_ip 先换算成等价范围再解析
+    StartPlusSize,
     Ipv4Num,
     Ipv6Num,
     UnknownIp,
@@ -23,24 +28,42 @@ impl IpTranslationType {
         if input.contains('-') {
             let parts: Vec<_> = input.split('-').collect();
             if parts.len() == 2 {
+                // 简写范围 a.b.c.d-e，展开后同样区分降序；结束段的合法性交给 translate_ip 报错
+                if let Ok(start) = Ipv4Addr::from_str(parts[0]) {
+                    if is_short_range_end(parts[1]) {
+                        return match expand_short_range_end(&start, parts[1]) {
+                            Ok(end) if end < start => Self::DescendingRange,
+                            _ => Self::Ipv4Range,
+                        };
+                    }
+                }
                 if let (Ok(start), Ok(end)) = (Ipv4Addr::from_str(parts[0]), Ipv4Addr::from_str(parts[1])) {
-                    // 验证是否真的是范围（start < end）
+                    // 验证是否真的是范围（start <= end，起止相同视为单地址范围）
                     let start_int = u32::from_be_bytes(start.octets()) as u128;
                     let end_int = u32::from_be_bytes(end.octets()) as u128;
-                    if start_int < end_int {
+                    if start_int <= end_int {
                         return Self::Ipv4Range;
                     }
+                    return Self::DescendingRange;
                 }
                 if let (Ok(start), Ok(end)) = (Ipv6Addr::from_str(parts[0]), Ipv6Addr::from_str(parts[1])) {
-                    // 验证是否真的是范围（start < end）
+                    // 验证是否真的是范围（start <= end，起止相同视为单地址范围）
                     let start_int = u128::from_be_bytes(start.octets());
                     let end_int = u128::from_be_bytes(end.octets());
-                    if start_int < end_int {
+                    if start_int <= end_int {
                         return Self::Ipv6Range;
                     }
+                    return Self::DescendingRange;
                 }
             }
         }
+        // 起始地址+数量 a+n 同样表示范围，数量只接受十进制数字，越界由 translate_ip 报错
+        if let Some((start, size)) = input.split_once('+') {
+            let is_address = Ipv4Addr::from_str(start.trim()).is_ok() || Ipv6Addr::from_str(start.trim()).is_ok();
+            if is_address && is_decimal_size(size.trim()) {
+                return Self::StartPlusSize;
+            }
+        }
 
         // 2. 掩码 CIDR 输入
         if input.contains('/') {
@@ -63,17 +86,25 @@ impl IpTranslationType {
             }
         }
 
-        // 3. 直接 IPv4
+        // 3. 直接 IPv4；点分或带 0b 前缀的 32 位二进制写法也按 IPv4 处理
         if let Ok(_ipv4) = Ipv4Addr::from_str(input) {
             return Self::Ipv4;
         }
+        if parse_binary_ipv4(input).is_some() {
+            return Self::Ipv4;
+        }
 
-        // 4. 直接 IPv6
-        if let Ok(_ipv6) = Ipv6Addr::from_str(input) {
+        // 4. 直接 IPv6（可带 %zone 区域标识，合法性由 translate_ip 校验）
+        if let Ok(_ipv6) = Ipv6Addr::from_str(split_zone(input).0) {
             return Self::Ipv6;
         }
 
-        // 5. 数字类型 IPv4Num 或 IPv6Num
+        // 5. 十六进制整数形式的 IPv4，如 0xC0A80001，按 IPv4Num 处理
+        if parse_hex_ipv4(input).is_some() {
+            return Self::Ipv4Num;
+        }
+
+        // 6. 数字类型 IPv4Num 或 IPv6Num
         if let Ok(num) = input.parse::<u128>() {
             if num <= u32::MAX as u128 {
                 return Self::Ipv4Num;
@@ -107,6 +138,18 @@ pub struct IpTranslationResult {
     pub broadcast_address: String,
     pub broadcast_address_int_value: String,
     pub broadcast_address_binary_address: String,
+    pub input_used_mixed_notation: bool, // IPv6 输入是否使用了嵌入 IPv4 的点分写法
+    pub usable_host_count: String,
+    pub first_usable_host: String,
+    pub last_usable_host: String,
+    pub is_point_to_point: bool, // IPv4 /31 点对点链路（RFC 3021）
+    pub total_addresses: String, // 块内地址总数，同样用字符串避免 JS 精度问题
+    pub ipv4_class: String, // 传统分类 A~E，IPv6 留空
+    pub scope: String, // 地址用途范围，如 private、loopback、global
+    pub zone_id: String, // IPv6 区域标识，如 fe80::1%eth0 中的 eth0
+    pub reverse_dns: String, // PTR 记录名，in-addr.arpa / ip6.arpa
+    pub embedded_mac: String, // EUI-64 接口标识还原出的 MAC，无 ff:fe 标记时留空
+    pub wildcard_mask: String, // 反掩码（Cisco ACL 用），IPv6 留空
 }
 
 impl Default for IpTranslationResult {
@@ -130,6 +173,18 @@ impl Default for IpTranslationResult {
             broadcast_address: "".into(),
             broadcast_address_int_value: "".into(),
             broadcast_address_binary_address: "".into(),
+            input_used_mixed_notation: false,
+            usable_host_count: "".into(),
+            first_usable_host: "".into(),
+            last_usable_host: "".into(),
+            is_point_to_point: false,
+            total_addresses: "".into(),
+            ipv4_class: "".into(),
+            scope: "".into(),
+            zone_id: "".into(),
+            reverse_dns: "".into(),
+            embedded_mac: "".into(),
+            wildcard_mask: "".into(),
         }
     }
 }
@@ -142,6 +197,147 @@ fn u32_to_ipv4(n: u32) -> Ipv4Addr {
     Ipv4Addr::from(n)
 }
 
+/// 32 位二进制形式的 IPv4，须按 8 位一段用点分隔（如 11000000.10101000.00000001.00000001），
+/// 或不分隔但带 0b 前缀；不带前缀的纯 0/1 串可能是十进制整数，不按二进制解析
+fn parse_binary_ipv4(input: &str) -> Option<Ipv4Addr> {
+    let input = input.trim();
+    let is_binary = |s: &str| s.chars().all(|c| c == '0' || c == '1');
+    let digits = if input.contains('.') {
+        let parts: Vec<&str> = input.split('.').collect();
+        if parts.len() != 4 || parts.iter().any(|p| p.len() != 8) {
+            return None;
+        }
+        parts.concat()
+    } else {
+        input
+            .strip_prefix("0b")
+            .or_else(|| input.strip_prefix("0B"))?
+            .to_string()
+    };
+    if digits.len() != 32 || !is_binary(&digits) {
+        return None;
+    }
+    u32::from_str_radix(&digits, 2).ok().map(u32_to_ipv4)
+}
+
+/// 多位数字且以 0 开头的段（如 010），inet_aton 会按八进制解析
+fn has_leading_zero(part: &str) -> bool {
+    part.len() > 1 && part.starts_with('0')
+}
+
+/// 点分四段且含前导零的段（如 010.0.0.1）时，返回去掉前导零后的十进制写法
+fn strip_leading_zero_octets(input: &str) -> Option<String> {
+    let parts: Vec<&str> = input.split('.').collect();
+    if parts.len() != 4 || !parts.iter().any(|p| has_leading_zero(p)) {
+        return None;
+    }
+    let octets = parts
+        .iter()
+        .map(|p| {
+            if p.is_empty() || !p.chars().all(|c| c.is_ascii_digit()) {
+                None
+            } else {
+                p.parse::<u8>().ok()
+            }
+        })
+        .collect::<Option<Vec<u8>>>()?;
+    Some(Ipv4Addr::new(octets[0], octets[1], octets[2], octets[3]).to_string())
+}
+
+/// 0x 前缀的十六进制整数形式 IPv4（最多 8 位十六进制），如 0xC0A80001
+fn parse_hex_ipv4(input: &str) -> Option<u32> {
+    let hex = input
+        .trim()
+        .strip_prefix("0x")
+        .or_else(|| input.trim().strip_prefix("0X"))?;
+    // from_str_radix 会接受 "+1" 这样的符号前缀，需先确认全是十六进制数字
+    if hex.is_empty() || hex.len() > 8 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    u32::from_str_radix(hex, 16).ok()
+}
+
+/// "起始地址+数量" 中的数量部分：非空且全是十进制数字（u128::from_str 会接受 "+5" 这类符号前缀）
+fn is_decimal_size(size: &str) -> bool {
+    !size.is_empty() && size.chars().all(|c| c.is_ascii_digit())
+}
+
+/// 简写范围 "10.0.0.1-5" 的结束部分：1~3 位纯数字
+fn is_short_range_end(end: &str) -> bool {
+    !end.is_empty() && end.len() <= 3 && end.chars().all(|c| c.is_ascii_digit())
+}
+
+/// 把简写范围的结束数字套用到起始地址的最后一段；含前导零的结束段有八进制歧义，直接拒绝
+fn expand_short_range_end(start: &Ipv4Addr, end: &str) -> Result<Ipv4Addr, String> {
+    if has_leading_zero(end) {
+        return Err(format!(
+            "简写范围的结束段 '{}' 含前导零：部分系统（inet_aton）会按八进制解析，存在歧义，已拒绝；如按十进制请输入 '{}'",
+            end,
+            end.parse::<u32>().unwrap_or_default()
+        ));
+    }
+    let last: u8 = end
+        .parse()
+        .map_err(|_| format!("简写范围的结束段 '{}' 超出 0~255 范围", end))?;
+    let octets = start.octets();
+    Ok(Ipv4Addr::new(octets[0], octets[1], octets[2], last))
+}
+
+/// 按首字节的前导位划分传统分类：A 0xxx、B 10xx、C 110x、D 1110（组播）、E 1111（保留）
+fn ipv4_class(ip: &Ipv4Addr) -> &'static str {
+    match ip.octets()[0] {
+        0..=127 => "A",
+        128..=191 => "B",
+        192..=223 => "C",
+        224..=239 => "D",
+        _ => "E",
+    }
+}
+
+/// IPv4 地址的用途范围，未命中任何特殊段时为 "global"
+fn ipv4_scope(ip: &Ipv4Addr) -> &'static str {
+    let octets = ip.octets();
+    if ip.is_unspecified() {
+        "unspecified"
+    } else if ip.is_broadcast() {
+        "broadcast"
+    } else if ip.is_loopback() {
+        "loopback"
+    } else if ip.is_private() {
+        "private"
+    } else if ip.is_link_local() {
+        "link-local"
+    } else if ip.is_multicast() {
+        "multicast"
+    } else if ip.is_documentation() {
+        "documentation"
+    } else if octets[0] == 100 && (octets[1] & 0xC0) == 64 {
+        "shared" // 100.64.0.0/10 运营商级 NAT
+    } else {
+        "global"
+    }
+}
+
+/// IPv6 特殊用途前缀 (前缀地址, 掩码长度, 范围名称)，按前缀从长到短排列，先匹配最具体的
+const IPV6_SCOPES: &[(u128, u8, &str)] = &[
+    (1, 128, "loopback"),
+    (0, 128, "unspecified"),
+    (0xFFFF_0000_0000, 96, "ipv4-mapped"),
+    (0xFE80 << 112, 10, "link-local"),
+    (0xFF00 << 112, 8, "multicast"),
+    (0xFC00 << 112, 7, "unique-local"),
+];
+
+/// IPv6 地址的用途范围，未命中任何特殊前缀时为 "global-unicast"
+fn ipv6_scope(ip: &Ipv6Addr) -> &'static str {
+    let num = ipv6_to_u128(ip);
+    IPV6_SCOPES
+        .iter()
+        .find(|&&(network, prefix, _)| cidr_bounds(num, prefix, 128).0 == network)
+        .map(|&(_, _, scope)| scope)
+        .unwrap_or("global-unicast")
+}
+
 fn format_ipv4_binary(ip: &Ipv4Addr) -> String {
     ip.octets()
         .iter()
@@ -212,6 +408,346 @@ fn format_ipv6_binary(ip: &Ipv6Addr) -> String {
     format!("{}\n{}", upper_half, lower_half)
 }
 
+/// IPv4 反向解析名，如 192.0.2.5 -> 5.2.0.192.in-addr.arpa
+fn ipv4_reverse_dns(ip: &Ipv4Addr) -> String {
+    let o = ip.octets();
+    format!("{}.{}.{}.{}.in-addr.arpa", o[3], o[2], o[1], o[0])
+}
+
+/// IPv6 反向解析名：完整展开形式的 32 个十六进制位倒序、以点分隔
+fn ipv6_reverse_dns(expanded: &str) -> String {
+    let nibbles: Vec<String> = expanded
+        .chars()
+        .filter(|c| *c != ':')
+        .rev()
+        .map(String::from)
+        .collect();
+    format!("{}.ip6.arpa", nibbles.join("."))
+}
+
+/// 从 EUI-64 接口标识（低 64 位中间为 ff:fe）还原 MAC，并翻转 U/L 位
+fn eui64_mac(ip: &Ipv6Addr) -> Option<String> {
+    let o = ip.octets();
+    if o[11] != 0xff || o[12] != 0xfe {
+        return None;
+    }
+    let mac = [o[8] ^ 0x02, o[9], o[10], o[13], o[14], o[15]];
+    Some(
+        mac.iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<Vec<String>>()
+            .join(":"),
+    )
+}
+
+/// 拆分 IPv6 的区域标识，如 "fe80::1%eth0" -> ("fe80::1", Some("eth0"))
+fn split_zone(input: &str) -> (&str, Option<&str>) {
+    match input.split_once('%') {
+        Some((address, zone)) => (address, Some(zone)),
+        None => (input, None),
+    }
+}
+
+/// 统计 IPv6 文本中显式写出的段数，嵌入的点分 IPv4 尾部计为 2 段
+fn ipv6_explicit_groups(input: &str) -> usize {
+    input
+        .split(':')
+        .filter(|group| !group.is_empty())
+        .map(|group| if group.contains('.') { 2 } else { 1 })
+        .sum()
+}
+
+/// 解析地址族名称，返回地址位数（IPv4 为 32，IPv6 为 128）
+fn parse_family(family: &str) -> Result<u8, String> {
+    match family.trim().to_ascii_lowercase().as_str() {
+        "ipv4" | "v4" | "4" => Ok(32),
+        "ipv6" | "v6" | "6" => Ok(128),
+        _ => Err(format!("未知的地址族 '{}'，应为 ipv4 或 ipv6", family)),
+    }
+}
+
+/// 块地址总数 2^host_bits 的十进制字符串，IPv6 /0 超出 u128 范围，用大数计算
+fn block_size_string(host_bits: u8) -> String {
+    (BigUint::from(1u8) << host_bits as usize).to_string()
+}
+
+/// IPv4 块的可用主机数，/31 按 RFC 3021 视为 2 个，/32 为 1 个
+fn ipv4_usable_hosts(prefix: u8) -> u64 {
+    match prefix {
+        32 => 1,
+        31 => 2,
+        _ => (1u64 << (32 - prefix)) - 2,
+    }
+}
+
+/// 能容纳 host_count 个主机的最长掩码（最小的块），IPv4 按可用主机数计算
+fn smallest_prefix_for_hosts(host_count: u64, bits: u8) -> Option<u8> {
+    (0..=bits).rev().find(|&prefix| {
+        // host_count 不超过 2^64，IPv6 最晚在 /64 命中，不会算到溢出的 /0
+        let capacity = if bits == 32 {
+            ipv4_usable_hosts(prefix) as u128
+        } else {
+            1u128 << (bits - prefix)
+        };
+        capacity >= host_count as u128
+    })
+}
+
+/// 闭区间 [起始, 结束] 的整数表示
+type Interval = (u128, u128);
+
+/// 解析单个 IPv4/IPv6 地址，返回 (整数值, 地址位数)
+fn parse_ip(input: &str) -> Result<(u128, u8), String> {
+    let input = input.trim();
+    if let Ok(ipv4) = Ipv4Addr::from_str(input) {
+        return Ok((ipv4_to_u32(&ipv4) as u128, 32));
+    }
+    if let Ok(ipv6) = Ipv6Addr::from_str(input) {
+        return Ok((ipv6_to_u128(&ipv6), 128));
+    }
+    // 与 IpTranslationType::from_str 一致，二进制写法按 IPv4 处理
+    if let Some(ipv4) = parse_binary_ipv4(input) {
+        return Ok((ipv4_to_u32(&ipv4) as u128, 32));
+    }
+    Err(format!("无法识别 IP 地址 '{}'", input))
+}
+
+/// 解析 CIDR，返回 (地址整数值, 掩码长度, 地址位数)，地址保留原始主机位
+fn parse_cidr(input: &str) -> Result<(u128, u8, u8), String> {
+    let parts: Vec<&str> = input.trim().split('/').collect();
+    if parts.len() != 2 {
+        return Err(format!("无效的 CIDR 格式 '{}'", input));
+    }
+    let (addr, bits) = parse_ip(parts[0])?;
+    let prefix: u8 = parts[1]
+        .trim()
+        .parse()
+        .map_err(|e| format!("无效的掩码长度 '{}': {}", parts[1], e))?;
+    if prefix > bits {
+        return Err(format!("掩码长度 '{}' 不能超过 {}", prefix, bits));
+    }
+    Ok((addr, prefix, bits))
+}
+
+/// 指定位数下的前缀掩码（IPv4 只占低 32 位）
+fn prefix_mask(prefix: u8, bits: u8) -> u128 {
+    if prefix == 0 {
+        0
+    } else {
+        (u128::MAX << (128 - prefix)) >> (128 - bits)
+    }
+}
+
+/// 主机位全为 1 的值，即块大小减一
+fn host_span(host_bits: u8) -> u128 {
+    if host_bits == 0 {
+        0
+    } else {
+        u128::MAX >> (128 - host_bits)
+    }
+}
+
+/// CIDR 覆盖的 (起始, 结束) 整数区间
+fn cidr_bounds(addr: u128, prefix: u8, bits: u8) -> Interval {
+    let network = addr & prefix_mask(prefix, bits);
+    (network, network | host_span(bits - prefix))
+}
+
+/// 按地址位数把整数格式化为 IPv4 或 IPv6 字符串
+fn format_addr(num: u128, bits: u8) -> String {
+    if bits == 32 {
+        u32_to_ipv4(num as u32).to_string()
+    } else {
+        u128_to_ipv6(num).to_string()
+    }
+}
+
+/// 把 [start, end] 区间拆成最少的对齐 CIDR 块，返回 (网络地址, 掩码长度)
+fn range_to_blocks(start: u128, end: u128, bits: u8) -> Vec<(u128, u8)> {
+    let mut blocks = Vec::new();
+    let mut current = start;
+    loop {
+        // 从当前地址能对齐的最大块开始，逐步缩小直到不超过 end
+        let mut host_bits = (current.trailing_zeros() as u8).min(bits);
+        while end - current < host_span(host_bits) {
+            host_bits -= 1;
+        }
+        blocks.push((current, bits - host_bits));
+
+        let last = current | host_span(host_bits);
+        if last >= end {
+            break;
+        }
+        current = last + 1;
+    }
+    blocks
+}
+
+/// 解析 a-b 形式的范围（支持 IPv4 简写 a.b.c.d-e），返回 (起始, 结束, 地址位数)
+fn parse_range(input: &str) -> Result<(u128, u128, u8), String> {
+    let parts: Vec<&str> = input.trim().split('-').collect();
+    if parts.len() != 2 {
+        return Err(format!("无效的范围格式 '{}'", input));
+    }
+    let (start, start_bits) = parse_ip(parts[0])?;
+    let (end, end_bits) = if start_bits == 32 && is_short_range_end(parts[1].trim()) {
+        let end_ip = expand_short_range_end(&u32_to_ipv4(start as u32), parts[1].trim())?;
+        (ipv4_to_u32(&end_ip) as u128, 32)
+    } else {
+        parse_ip(parts[1])?
+    };
+    if start_bits != end_bits {
+        return Err(format!("范围 '{}' 的起止地址地址族不一致", input));
+    }
+    if start > end {
+        return Err(format!(
+            "范围起始地址 '{}' 不能大于结束地址 '{}'",
+            parts[0].trim(),
+            parts[1].trim()
+        ));
+    }
+    Ok((start, end, start_bits))
+}
+
+/// 解析仅限 IPv4 的 a-b 范围
+fn parse_ipv4_range(input: &str) -> Result<(u32, u32), String> {
+    let (start, end, bits) = parse_range(input)?;
+    if bits != 32 {
+        return Err(format!("范围 '{}' 不是 IPv4 范围", input.trim()));
+    }
+    Ok((start as u32, end as u32))
+}
+
+/// 解析 CIDR 或 a-b 范围，统一返回 (起始, 结束, 地址位数)
+fn parse_block(input: &str) -> Result<(u128, u128, u8), String> {
+    if input.contains('/') {
+        let (addr, prefix, bits) = parse_cidr(input)?;
+        let (network, broadcast) = cidr_bounds(addr, prefix, bits);
+        Ok((network, broadcast, bits))
+    } else {
+        parse_range(input)
+    }
+}
+
+/// 能同时包含 start 和 end 的最小 CIDR，返回 (网络地址, 掩码长度)
+fn covering_cidr(start: u128, end: u128, bits: u8) -> (u128, u8) {
+    let host_bits = (128 - (start ^ end).leading_zeros()) as u8;
+    (start & !host_span(host_bits), bits - host_bits)
+}
+
+/// 两个同族地址从最高位起相同的位数
+fn common_prefix_len(a: u128, b: u128, bits: u8) -> u8 {
+    ((a ^ b).leading_zeros() - (128 - bits as u32)).min(bits as u32) as u8
+}
+
+/// 两个区间是否有公共地址
+fn intervals_overlap(a: Interval, b: Interval) -> bool {
+    a.0 <= b.1 && b.0 <= a.1
+}
+
+/// 排序并合并重叠或相邻的区间
+fn merge_intervals(mut intervals: Vec<Interval>) -> Vec<Interval> {
+    intervals.sort();
+    let mut merged: Vec<Interval> = Vec::new();
+    for (start, end) in intervals {
+        if let Some(last) = merged.last_mut() {
+            if last.1 == u128::MAX || start <= last.1 + 1 {
+                last.1 = last.1.max(end);
+                continue;
+            }
+        }
+        merged.push((start, end));
+    }
+    merged
+}
+
+/// 从区间集合 a 中减去区间集合 b（两者均需已合并排序）
+fn subtract_intervals(a: &[Interval], b: &[Interval]) -> Vec<Interval> {
+    let mut result = Vec::new();
+    for &(start, end) in a {
+        let mut current = start;
+        let mut exhausted = false;
+        for &(ex_start, ex_end) in b {
+            if ex_end < current || ex_start > end {
+                continue;
+            }
+            if ex_start > current {
+                result.push((current, ex_start - 1));
+            }
+            if ex_end >= end {
+                exhausted = true;
+                break;
+            }
+            current = ex_end + 1;
+        }
+        if !exhausted {
+            result.push((current, end));
+        }
+    }
+    result
+}
+
+/// 区间集合包含的地址总数，整个 IPv6 空间会超出 u128，用大数累加
+fn intervals_size_string(intervals: &[Interval]) -> String {
+    intervals
+        .iter()
+        .fold(BigUint::from(0u8), |acc, &(start, end)| {
+            acc + BigUint::from(end - start) + BigUint::from(1u8)
+        })
+        .to_string()
+}
+
+/// 解析 CIDR 列表为合并后的区间，要求同一地址族；空列表返回 None 作为地址族
+fn parse_cidr_list(cidrs: &[String]) -> Result<(Vec<Interval>, Option<u8>), String> {
+    let mut family: Option<u8> = None;
+    let mut intervals = Vec::new();
+    for cidr in cidrs {
+        let (addr, prefix, bits) = parse_cidr(cidr)?;
+        if family.is_some_and(|f| f != bits) {
+            return Err(format!("CIDR '{}' 与列表中其他地址的地址族不一致", cidr));
+        }
+        family = Some(bits);
+        intervals.push(cidr_bounds(addr, prefix, bits));
+    }
+    Ok((merge_intervals(intervals), family))
+}
+
+/// 把区间集合转成最少的 CIDR 字符串列表
+fn intervals_to_cidrs(intervals: &[Interval], bits: u8) -> Vec<String> {
+    intervals
+        .iter()
+        .flat_map(|&(start, end)| range_to_blocks(start, end, bits))
+        .map(|(network, prefix)| format!("{}/{}", format_addr(network, bits), prefix))
+        .collect()
+}
+
+/// 合并 CIDR 列表（相邻、重叠都会合并），返回最少的 CIDR 集合，要求同一地址族
+fn aggregate_cidr_list(cidrs: &[String]) -> Result<Vec<String>, String> {
+    let (intervals, family) = parse_cidr_list(cidrs)?;
+    Ok(match family {
+        Some(bits) => intervals_to_cidrs(&intervals, bits),
+        None => Vec::new(),
+    })
+}
+
+/// 把单个地址列表合并成最少的 CIDR 集合，要求同一地址族
+fn ips_to_cidrs(ips: &[String]) -> Result<Vec<String>, String> {
+    let mut family: Option<u8> = None;
+    let mut intervals = Vec::with_capacity(ips.len());
+    for ip in ips {
+        let (num, bits) = parse_ip(ip)?;
+        if family.is_some_and(|f| f != bits) {
+            return Err(format!("地址 '{}' 与列表中其他地址的地址族不一致", ip));
+        }
+        family = Some(bits);
+        intervals.push((num, num));
+    }
+    Ok(match family {
+        Some(bits) => intervals_to_cidrs(&merge_intervals(intervals), bits),
+        None => Vec::new(),
+    })
+}
+
 #[tauri::command]
 pub fn translate_ip(ip: String) -> Result<IpTranslationResult, String> {
     let ip_type = IpTranslationType::from_str(&ip);
@@ -219,9 +755,13 @@ pub fn translate_ip(ip: String) -> Result<IpTranslationResult, String> {
     
     match ip_type {
         IpTranslationType::Ipv4 => {
-            let ipv4 = Ipv4Addr::from_str(&ip).map_err(|e| format!("无效的 IPv4 地址 '{}': {}", ip, e))?;
+            let binary = parse_binary_ipv4(&ip);
+            let ipv4 = match binary {
+                Some(ipv4) => ipv4,
+                None => Ipv4Addr::from_str(&ip).map_err(|e| format!("无效的 IPv4 地址 '{}': {}", ip, e))?,
+            };
             let int_value = ipv4_to_u32(&ipv4) as u128;
-            result.field_type = "IPv4".into();
+            result.field_type = if binary.is_some() { "二进制 -> IPv4" } else { "IPv4" }.into();
             result.com_address = ipv4.to_string();
             result.binary_address = format_ipv4_binary(&ipv4);
             // 上一个地址
@@ -236,11 +776,26 @@ pub fn translate_ip(ip: String) -> Result<IpTranslationResult, String> {
             result.to_ipv4 = ipv4.to_string();
             result.to_ipv6 = format!("0:0:0:0:0:ffff:{}", ipv4);
             result.high_low_64_bit_signed_number = Some(split_u128_to_i64(int_value));
+            result.ipv4_class = ipv4_class(&ipv4).into();
+            result.scope = ipv4_scope(&ipv4).into();
+            result.reverse_dns = ipv4_reverse_dns(&ipv4);
         }
         IpTranslationType::Ipv6 => {
-            let ipv6 = Ipv6Addr::from_str(&ip).map_err(|e| format!("无效的 IPv6 地址 '{}': {}", ip, e))?;
+            let (address, zone) = split_zone(&ip);
+            let ipv6 = Ipv6Addr::from_str(address).map_err(|e| format!("无效的 IPv6 地址 '{}': {}", ip, e))?;
             let int_value = ipv6_to_u128(&ipv6);
 
+            // 区域标识只对链路本地地址有意义
+            if let Some(zone) = zone {
+                if zone.is_empty() {
+                    return Err(format!("IPv6 地址 '{}' 的区域标识为空", ip));
+                }
+                if ipv6_scope(&ipv6) != "link-local" {
+                    return Err(format!("区域标识 '%{}' 仅适用于链路本地地址（fe80::/10），'{}' 不是链路本地地址", zone, ipv6));
+                }
+                result.zone_id = zone.into();
+            }
+
             result.field_type = "IPv6".into();
             result.com_address = ipv6.to_string(); // 压缩形式
             result.ex_address = ipv6
@@ -249,6 +804,9 @@ pub fn translate_ip(ip: String) -> Result<IpTranslationResult, String> {
                 .map(|seg| format!("{:04x}", seg))
                 .collect::<Vec<String>>()
                 .join(":"); // 完整展开形式
+            result.reverse_dns = ipv6_reverse_dns(&result.ex_address);
+            result.embedded_mac = eui64_mac(&ipv6).unwrap_or_default();
+            result.input_used_mixed_notation = address.contains('.');
 
             result.binary_address = format_ipv6_binary(&ipv6);
             result.int_value = int_value.to_string();
@@ -264,6 +822,7 @@ pub fn translate_ip(ip: String) -> Result<IpTranslationResult, String> {
                 result.next_address = u128_to_ipv6(int_value + 1).to_string();
             }
             result.to_ipv6 = ipv6.to_string();
+            result.scope = ipv6_scope(&ipv6).into();
         }
         IpTranslationType::Ipv4Mask => {
             let parts: Vec<&str> = ip.split('/').collect();
@@ -288,6 +847,7 @@ pub fn translate_ip(ip: String) -> Result<IpTranslationResult, String> {
             result.com_address = ipv4.to_string();
             result.subnet = format!("{}/{}", ipv4, mask_len);
             result.subnet_mask = u32_to_ipv4(mask).to_string();
+            result.wildcard_mask = u32_to_ipv4(!mask).to_string();
             result.net_work_address = u32_to_ipv4(network).to_string();
             result.broadcast_address = u32_to_ipv4(broadcast).to_string();
             result.int_value = ip_u32.to_string();
@@ -299,6 +859,25 @@ pub fn translate_ip(ip: String) -> Result<IpTranslationResult, String> {
             result.broadcast_address_binary_address = format_ipv4_binary(&u32_to_ipv4(broadcast));
             result.to_ipv4 = ipv4.to_string();
             result.to_ipv6 = format!("0:0:0:0:0:ffff:{}", ipv4);
+
+            // 可用主机：/31 按 RFC 3021 两个地址都可用，/32 只有自身
+            let (first_usable, last_usable) = if mask_len >= 31 {
+                (network, broadcast)
+            } else {
+                (network + 1, broadcast - 1)
+            };
+            result.total_addresses = block_size_string(32 - mask_len);
+            result.usable_host_count = ipv4_usable_hosts(mask_len).to_string();
+            result.first_usable_host = u32_to_ipv4(first_usable).to_string();
+            result.last_usable_host = u32_to_ipv4(last_usable).to_string();
+
+            // /31 点对点链路没有广播地址，/32 单主机也不区分网络/广播地址，只保留网络地址
+            result.is_point_to_point = mask_len == 31;
+            if mask_len >= 31 {
+                result.broadcast_address = "".into();
+                result.broadcast_address_int_value = "".into();
+                result.broadcast_address_binary_address = "".into();
+            }
         }
         IpTranslationType::Ipv6Mask => {
             let parts: Vec<&str> = ip.split('/').collect();
@@ -338,7 +917,8 @@ pub fn translate_ip(ip: String) -> Result<IpTranslationResult, String> {
                 .join(":");
 
             result.subnet = format!("{}/{}", ipv6, mask_len);
-            result.subnet_mask = format!("{:x}", mask); // IPv6一般用CIDR，不展示传统掩码
+            result.total_addresses = block_size_string(128 - mask_len);
+            result.subnet_mask = u128_to_ipv6(mask).to_string(); // 按 IPv6 地址格式分组显示
             result.net_work_address = u128_to_ipv6(network).to_string();
             result.net_work_address_int_value = network.to_string();
             result.net_work_address_binary_address = format_ipv6_binary(&u128_to_ipv6(network));
@@ -355,14 +935,18 @@ pub fn translate_ip(ip: String) -> Result<IpTranslationResult, String> {
             result.to_ipv4 = "".into(); // IPv6Mask无法转IPv4
         }
         IpTranslationType::Ipv4Range => {
-            // 解析 a.b.c.d-e.f.g.h
+            // 解析 a.b.c.d-e.f.g.h 或简写的 a.b.c.d-e
             let parts: Vec<&str> = ip.split('-').collect();
             if parts.len() != 2 {
                 return Err(format!("无效的 IPv4 范围格式 '{}'", ip));
             }
 
             let start_ip = Ipv4Addr::from_str(parts[0].trim()).map_err(|e| format!("起始 IPv4 地址 '{}' 无效: {}", parts[0], e))?;
-            let end_ip = Ipv4Addr::from_str(parts[1].trim()).map_err(|e| format!("结束 IPv4 地址 '{}' 无效: {}", parts[1], e))?;
+            let end_ip = if is_short_range_end(parts[1].trim()) {
+                expand_short_range_end(&start_ip, parts[1].trim())?
+            } else {
+                Ipv4Addr::from_str(parts[1].trim()).map_err(|e| format!("结束 IPv4 地址 '{}' 无效: {}", parts[1], e))?
+            };
 
             let start_int = ipv4_to_u32(&start_ip) as u128;
             let end_int = ipv4_to_u32(&end_ip) as u128;
@@ -387,6 +971,8 @@ pub fn translate_ip(ip: String) -> Result<IpTranslationResult, String> {
             result.broadcast_address = end_ip.to_string();
             result.broadcast_address_int_value = end_int.to_string();
             result.broadcast_address_binary_address = format_ipv4_binary(&end_ip);
+            // 起止相同时为 1
+            result.total_addresses = (end_int - start_int + 1).to_string();
         }
         IpTranslationType::Ipv6Range => {
             // 解析 a:b:c::d - a:b:c::e
@@ -421,15 +1007,32 @@ pub fn translate_ip(ip: String) -> Result<IpTranslationResult, String> {
                 format_ipv6_binary(&start_ip),
                 format_ipv6_binary(&end_ip)
             );
+            // 整个 IPv6 空间的数量超出 u128，用大数计算
+            result.total_addresses = (BigUint::from(end_int - start_int) + BigUint::from(1u8)).to_string();
+        }
+        IpTranslationType::StartPlusSize => {
+            let mut range_result = translate_ip(parse_start_plus_size(ip.clone())?)?;
+            range_result.field_type = format!("起始地址+数量 -> {}", range_result.field_type);
+            range_result.ex_address = ip.trim().into(); // 保留原始写法
+            return Ok(range_result);
         }
         IpTranslationType::Ipv4Num => {
-            let num = ip.parse::<u128>().map_err(|e| format!("无效的 IPv4 数字 '{}': {}", ip, e))?;
+            let hex = parse_hex_ipv4(&ip);
+            let num = match hex {
+                Some(n) => n as u128,
+                None => ip.parse::<u128>().map_err(|e| format!("无效的 IPv4 数字 '{}': {}", ip, e))?,
+            };
             if num > u32::MAX as u128 {
                 return Err(format!("IPv4 数值 '{}' 必须在 0 ~ 2^32-1 之间", num));
             }
             let ipv4 = u32_to_ipv4(num as u32);
             
-            result.field_type = "整数 -> IPv4".into();
+            if hex.is_some() {
+                result.field_type = "十六进制 -> IPv4".into();
+                result.ex_address = ip.trim().into(); // 保留原始十六进制写法
+            } else {
+                result.field_type = "整数 -> IPv4".into();
+            }
             result.com_address = ipv4.to_string();
             result.binary_address = format_ipv4_binary(&ipv4);
             result.int_value = num.to_string();
@@ -445,6 +1048,9 @@ pub fn translate_ip(ip: String) -> Result<IpTranslationResult, String> {
             result.to_ipv4 = ipv4.to_string();
             result.to_ipv6 = format!("::ffff:{}", ipv4);
             result.high_low_64_bit_signed_number = Some(split_u128_to_i64(num));
+            result.ipv4_class = ipv4_class(&ipv4).into();
+            result.scope = ipv4_scope(&ipv4).into();
+            result.reverse_dns = ipv4_reverse_dns(&ipv4);
         }
         IpTranslationType::Ipv6Num => {
             let num = ip.parse::<u128>().map_err(|e| format!("无效的 IPv6 数字 '{}': {}", ip, e))?;
@@ -458,6 +1064,8 @@ pub fn translate_ip(ip: String) -> Result<IpTranslationResult, String> {
                 .map(|seg| format!("{:04x}", seg))
                 .collect::<Vec<String>>()
                 .join(":");
+            result.reverse_dns = ipv6_reverse_dns(&result.ex_address);
+            result.embedded_mac = eui64_mac(&ipv6).unwrap_or_default();
             result.binary_address = format_ipv6_binary(&ipv6);
             result.int_value = num.to_string();
             result.high_low_64_bit_signed_number = Some(split_u128_to_i64(num));
@@ -469,10 +1077,2997 @@ pub fn translate_ip(ip: String) -> Result<IpTranslationResult, String> {
                 result.next_address = u128_to_ipv6(num + 1).to_string();
             }
             result.to_ipv6 = ipv6.to_string();
+            result.scope = ipv6_scope(&ipv6).into();
+        }
+        IpTranslationType::DescendingRange => {
+            // 不自动交换端点，避免把输入错误悄悄变成另一个范围
+            let (start, end) = ip.trim().split_once('-').unwrap_or_default();
+            let (start, mut end) = (start.trim(), end.trim().to_string());
+            // 简写 a.b.c.d-e 的建议写法需要用展开后的结束地址
+            if let Ok(start_ip) = Ipv4Addr::from_str(start) {
+                if let Ok(end_ip) = expand_short_range_end(&start_ip, &end) {
+                    end = end_ip.to_string();
+                }
+            }
+            return Err(format!(
+                "范围是降序的 '{}'：起始地址大于结束地址，是否想输入 '{}-{}'",
+                ip, end, start
+            ));
         }
         IpTranslationType::UnknownIp => {
+            // "::" 至少代表一段，因此带 "::" 时显式段数不能超过 7
+            let trimmed = ip.trim();
+            if trimmed.contains(':') {
+                let groups = ipv6_explicit_groups(trimmed);
+                if groups > 8 || (trimmed.contains("::") && groups > 7) {
+                    return Err(format!("IPv6 段数超出 '{}': 共 {} 段（嵌入的 IPv4 计为 2 段），最多 8 段", ip, groups));
+                }
+            }
+            // 标准库拒绝前导零的段，这里单独说明原因，避免与 inet_aton 的八进制解释混淆；范围的两端分别检查
+            let (address, prefix) = match trimmed.split_once('/') {
+                Some((address, prefix)) => (address, Some(prefix)),
+                None => (trimmed, None),
+            };
+            let decimal = match address.split_once('-') {
+                Some((start, end)) => {
+                    let (start, end) = (start.trim(), end.trim());
+                    match (strip_leading_zero_octets(start), strip_leading_zero_octets(end)) {
+                        (None, None) => None,
+                        (new_start, new_end) => Some(format!(
+                            "{}-{}",
+                            new_start.unwrap_or_else(|| start.into()),
+                            new_end.unwrap_or_else(|| end.into())
+                        )),
+                    }
+                }
+                None => strip_leading_zero_octets(address),
+            };
+            if let Some(decimal) = decimal {
+                let suggestion = match prefix {
+                    Some(prefix) => format!("{}/{}", decimal, prefix),
+                    None => decimal,
+                };
+                return Err(format!(
+                    "IPv4 地址 '{}' 含前导零的段：部分系统（inet_aton）会按八进制解析（如 010 为 8），存在歧义，已拒绝；如按十进制请输入 '{}'",
+                    ip, suggestion
+                ));
+            }
+            if let Some(hint) = suggest_fix(ip.clone()) {
+                return Err(format!("无法识别 IP 格式 '{}'，{}", ip, hint));
+            }
             return Err(format!("无法识别 IP 格式 '{}'", ip));
         }
     }
     Ok(result)
-}
\ No newline at end of file
+}
+#[tauri::command]
+pub fn parse_hex_octets(input: String) -> Result<String, String> {
+    // 解析 0xC0.0xA8.0x01.0x01 形式，每段按十六进制读取
+    let parts: Vec<&str> = input.trim().split('.').collect();
+    if parts.len() != 4 {
+        return Err(format!("无效的十六进制 IPv4 格式 '{}'，需要 4 段", input));
+    }
+
+    let mut octets = [0u8; 4];
+    for (i, part) in parts.iter().enumerate() {
+        // 每段都必须带 0x 前缀，否则 "10.0.0.1" 这类十进制输入会被误读为十六进制
+        let hex = part
+            .strip_prefix("0x")
+            .or_else(|| part.strip_prefix("0X"))
+            .ok_or_else(|| format!("第 {} 段 '{}' 缺少 0x 前缀", i + 1, part))?;
+        if hex.is_empty() || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(format!("第 {} 段十六进制值 '{}' 无效", i + 1, part));
+        }
+        octets[i] = u8::from_str_radix(hex, 16)
+            .map_err(|e| format!("第 {} 段十六进制值 '{}' 无效: {}", i + 1, part, e))?;
+    }
+    Ok(Ipv4Addr::from(octets).to_string())
+}
+
+#[tauri::command]
+pub fn cidr_symmetric_difference(a: Vec<String>, b: Vec<String>) -> Result<Vec<String>, String> {
+    let (a_intervals, a_family) = parse_cidr_list(&a)?;
+    let (b_intervals, b_family) = parse_cidr_list(&b)?;
+    let bits = match (a_family, b_family) {
+        (Some(fa), Some(fb)) if fa != fb => {
+            return Err("两个 CIDR 集合的地址族不一致".into());
+        }
+        (Some(f), _) | (_, Some(f)) => f,
+        (None, None) => return Ok(Vec::new()),
+    };
+
+    // 只出现在其中一个集合里的地址 = (A - B) ∪ (B - A)
+    let mut only_one = subtract_intervals(&a_intervals, &b_intervals);
+    only_one.extend(subtract_intervals(&b_intervals, &a_intervals));
+    Ok(intervals_to_cidrs(&merge_intervals(only_one), bits))
+}
+
+/// 把地址映射到 Hilbert 曲线坐标，用于 IP 空间热力图。
+/// order 阶曲线是 2^order × 2^order 的网格，只取地址最高的 2*order 位作为曲线序号，
+/// 例如 IPv4 取 order=12 时每个格子对应一个 /24。
+#[tauri::command]
+pub fn hilbert_xy(ip: String, order: u32) -> Result<(u32, u32), String> {
+    let (addr, bits) = parse_ip(&ip)?;
+    if order == 0 || order > 32 || order * 2 > bits as u32 {
+        return Err(format!(
+            "Hilbert 曲线阶数 '{}' 无效，需在 1 ~ {} 之间",
+            order,
+            (bits as u32 / 2).min(32)
+        ));
+    }
+
+    let mut t = (addr >> (bits as u32 - order * 2)) as u64;
+    let (mut x, mut y) = (0u64, 0u64);
+    let mut s = 1u64;
+    while s < (1u64 << order) {
+        let rx = 1 & (t / 2);
+        let ry = 1 & (t ^ rx);
+        // 按象限旋转/翻转
+        if ry == 0 {
+            if rx == 1 {
+                x = s - 1 - x;
+                y = s - 1 - y;
+            }
+            std::mem::swap(&mut x, &mut y);
+        }
+        x += s * rx;
+        y += s * ry;
+        t /= 4;
+        s *= 2;
+    }
+    Ok((x as u32, y as u32))
+}
+
+#[tauri::command]
+pub fn well_known(name: String) -> Result<String, String> {
+    let address = match name.trim().to_ascii_lowercase().as_str() {
+        "ipv4-any" => Ipv4Addr::UNSPECIFIED.to_string(),
+        "ipv4-broadcast" => Ipv4Addr::BROADCAST.to_string(),
+        "ipv4-loopback" => Ipv4Addr::LOCALHOST.to_string(),
+        "ipv4-all-hosts" => Ipv4Addr::new(224, 0, 0, 1).to_string(),
+        "ipv4-all-routers" => Ipv4Addr::new(224, 0, 0, 2).to_string(),
+        "ipv6-any" => Ipv6Addr::UNSPECIFIED.to_string(),
+        "ipv6-loopback" => Ipv6Addr::LOCALHOST.to_string(),
+        "ipv6-all-nodes" => Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 1).to_string(),
+        "ipv6-all-routers" => Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 2).to_string(),
+        _ => return Err(format!("未知的知名地址名称 '{}'", name)),
+    };
+    Ok(address)
+}
+
+/// 在块数不超过 k 的前提下，用 network/host_bits 块内的 CIDR 覆盖 [start, end]，
+/// 返回 (多覆盖的地址数, 块列表)，多覆盖数越小越好
+fn cover_with_budget(network: u128, host_bits: u8, bits: u8, start: u128, end: u128, k: usize) -> (u128, Vec<(u128, u8)>) {
+    let block_end = network | host_span(host_bits);
+    let lo = start.max(network);
+    let hi = end.min(block_end);
+    let whole = (host_span(host_bits) - (hi - lo), vec![(network, bits - host_bits)]);
+    if whole.0 == 0 {
+        return whole;
+    }
+
+    // 范围只落在一半里时，先下探到那一半，再考虑预算为 1 时整块覆盖
+    let right = (network | host_span(host_bits - 1)) + 1;
+    if hi < right {
+        return cover_with_budget(network, host_bits - 1, bits, start, end, k);
+    }
+    if lo >= right {
+        return cover_with_budget(right, host_bits - 1, bits, start, end, k);
+    }
+    if k == 1 {
+        return whole;
+    }
+
+    // 完整的一半只需要 1 块，其余预算全部留给另一半
+    let budgets: Vec<usize> = if lo == network {
+        vec![1]
+    } else if hi == block_end {
+        vec![k - 1]
+    } else {
+        (1..k).collect()
+    };
+
+    let mut best = whole;
+    for left_k in budgets {
+        let (left_waste, mut blocks) = cover_with_budget(network, host_bits - 1, bits, start, end, left_k);
+        let (right_waste, right_blocks) = cover_with_budget(right, host_bits - 1, bits, start, end, k - left_k);
+        if left_waste + right_waste < best.0 {
+            blocks.extend(right_blocks);
+            best = (left_waste + right_waste, blocks);
+        }
+    }
+    best
+}
+
+/// 精确拆分不超过 max_blocks 块时返回精确结果，否则返回多覆盖地址最少的 max_blocks 块以内的 CIDR
+#[tauri::command]
+pub fn range_to_cidrs_limited(range: String, max_blocks: usize) -> Result<Vec<String>, String> {
+    if max_blocks == 0 {
+        return Err("最大块数必须大于 0".into());
+    }
+    let (start, end, bits) = parse_range(&range)?;
+
+    let exact = range_to_blocks(start, end, bits);
+    let blocks = if exact.len() <= max_blocks {
+        exact
+    } else {
+        let (network, prefix) = covering_cidr(start, end, bits);
+        cover_with_budget(network, bits - prefix, bits, start, end, max_blocks).1
+    };
+    Ok(blocks
+        .into_iter()
+        .map(|(network, prefix)| format!("{}/{}", format_addr(network, bits), prefix))
+        .collect())
+}
+
+#[tauri::command]
+pub fn octets(ip: String) -> Result<Vec<u16>, String> {
+    let input = ip.trim();
+    if let Ok(ipv4) = Ipv4Addr::from_str(input) {
+        return Ok(ipv4.octets().iter().map(|&oct| oct as u16).collect());
+    }
+    if let Ok(ipv6) = Ipv6Addr::from_str(input) {
+        return Ok(ipv6.segments().to_vec());
+    }
+    Err(format!("无法识别 IP 地址 '{}'", ip))
+}
+
+#[tauri::command]
+pub fn range_as_single_cidr(range: String) -> Result<String, String> {
+    let (start, end, bits) = parse_range(&range)?;
+    let blocks = range_to_blocks(start, end, bits);
+    if blocks.len() != 1 {
+        return Err(format!(
+            "范围 '{}' 未按 2 的幂对齐，无法用单个 CIDR 表示（需要 {} 个）",
+            range.trim(),
+            blocks.len()
+        ));
+    }
+    let (network, prefix) = blocks[0];
+    Ok(format!("{}/{}", format_addr(network, bits), prefix))
+}
+
+/// 主机在所在块中的位置比例：主机偏移 / (块大小 - 1)，/32、/128 单地址块固定返回 0
+#[tauri::command]
+pub fn host_position_fraction(cidr_host: String) -> Result<f64, String> {
+    let (addr, prefix, bits) = parse_cidr(&cidr_host)?;
+    let (network, broadcast) = cidr_bounds(addr, prefix, bits);
+    if broadcast == network {
+        return Ok(0.0);
+    }
+    Ok((addr - network) as f64 / (broadcast - network) as f64)
+}
+
+#[tauri::command]
+pub fn address_before_block(cidr: String) -> Result<String, String> {
+    let (addr, prefix, bits) = parse_cidr(&cidr)?;
+    let (network, _) = cidr_bounds(addr, prefix, bits);
+    if network == 0 {
+        return Err(format!("CIDR '{}' 已从地址空间起点开始，之前没有地址", cidr.trim()));
+    }
+    Ok(format_addr(network - 1, bits))
+}
+
+/// 范围拆分成 CIDR 后各块的掩码长度，按范围顺序排列
+#[tauri::command]
+pub fn range_cidr_sizes(range: String) -> Result<Vec<u8>, String> {
+    let (start, end, bits) = parse_range(&range)?;
+    Ok(range_to_blocks(start, end, bits)
+        .into_iter()
+        .map(|(_, prefix)| prefix)
+        .collect())
+}
+
+#[tauri::command]
+pub fn network_of(ip: String, prefix: u8) -> Result<String, String> {
+    let (addr, bits) = parse_ip(&ip)?;
+    if prefix > bits {
+        return Err(format!("掩码长度 '{}' 不能超过 {}", prefix, bits));
+    }
+    let (network, _) = cidr_bounds(addr, prefix, bits);
+    Ok(format_addr(network, bits))
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PrefixRow {
+    pub prefix: u8,
+    pub addresses: String,
+    pub usable: String, // 仅 IPv4 计算可用主机数，IPv6 留空
+}
+
+#[tauri::command]
+pub fn prefix_table(family: String) -> Result<Vec<PrefixRow>, String> {
+    let bits = parse_family(&family)?;
+    Ok((0..=bits)
+        .map(|prefix| PrefixRow {
+            prefix,
+            addresses: block_size_string(bits - prefix),
+            usable: if bits == 32 {
+                ipv4_usable_hosts(prefix).to_string()
+            } else {
+                "".into()
+            },
+        })
+        .collect())
+}
+
+/// 点分四段且某段为 256~999 时，指出越界的段并给出截断到 255 的建议地址
+#[tauri::command]
+pub fn suggest_fix(input: String) -> Option<String> {
+    let parts: Vec<&str> = input.trim().split('.').collect();
+    if parts.len() != 4 {
+        return None;
+    }
+
+    let mut out_of_range = Vec::new();
+    let mut fixed = Vec::new();
+    for (i, part) in parts.iter().enumerate() {
+        if part.is_empty() || part.len() > 3 || !part.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let value: u16 = part.parse().ok()?;
+        if value > 255 {
+            out_of_range.push(format!("第 {} 段 '{}'", i + 1, part));
+            fixed.push("255".to_string());
+        } else {
+            fixed.push(value.to_string());
+        }
+    }
+    if out_of_range.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "{} 超出 0~255 范围，建议改为 {}",
+        out_of_range.join("、"),
+        fixed.join(".")
+    ))
+}
+
+#[tauri::command]
+pub fn touched_slash24s(range: String) -> Result<Vec<String>, String> {
+    let (start, end) = parse_ipv4_range(&range)?;
+    Ok(((start >> 8)..=(end >> 8))
+        .map(|block| format!("{}/24", u32_to_ipv4(block << 8)))
+        .collect())
+}
+
+/// 列出地址恰好是网络地址或广播地址的所有掩码长度。
+/// IPv4 /31 按 RFC 3021 标记为 "point-to-point"，整段长度（/32、/128）为单地址，不列出
+#[tauri::command]
+pub fn boundary_roles(ip: String) -> Result<Vec<(u8, String)>, String> {
+    let (addr, bits) = parse_ip(&ip)?;
+    let mut roles = Vec::new();
+    for prefix in 0..bits {
+        let (network, broadcast) = cidr_bounds(addr, prefix, bits);
+        let role = if bits == 32 && prefix == 31 {
+            "point-to-point"
+        } else if addr == network {
+            "network"
+        } else if addr == broadcast {
+            "broadcast"
+        } else {
+            continue;
+        };
+        roles.push((prefix, role.to_string()));
+    }
+    Ok(roles)
+}
+
+/// 自检：translate_ip 得到的 int_value 转回地址后应与规范地址一致
+#[tauri::command]
+pub fn verify_roundtrip(ip: String) -> Result<bool, String> {
+    let bits = match IpTranslationType::from_str(&ip) {
+        IpTranslationType::Ipv4 | IpTranslationType::Ipv4Mask | IpTranslationType::Ipv4Num => 32,
+        IpTranslationType::Ipv6 | IpTranslationType::Ipv6Mask | IpTranslationType::Ipv6Num => 128,
+        _ => return Err(format!("'{}' 不是单个地址，无法做往返校验", ip)),
+    };
+    let result = translate_ip(ip)?;
+    let int_value: u128 = result
+        .int_value
+        .parse()
+        .map_err(|e| format!("整数值 '{}' 无效: {}", result.int_value, e))?;
+    Ok(format_addr(int_value, bits) == result.com_address)
+}
+
+/// 完整展开后的 32 个十六进制半字节（0-15），从高位到低位
+#[tauri::command]
+pub fn ipv6_nibbles(ip: String) -> Result<Vec<u8>, String> {
+    let ipv6 = Ipv6Addr::from_str(ip.trim()).map_err(|e| format!("无效的 IPv6 地址 '{}': {}", ip, e))?;
+    let num = ipv6_to_u128(&ipv6);
+    Ok((0..32).rev().map(|i| ((num >> (i * 4)) & 0xF) as u8).collect())
+}
+
+/// 是否属于运营商级 NAT 共享地址段 100.64.0.0/10（RFC 6598）
+#[tauri::command]
+pub fn is_cgnat(ip: String) -> Result<bool, String> {
+    let (addr, bits) = parse_ip(&ip)?;
+    if bits != 32 {
+        return Err(format!("CGNAT 仅适用于 IPv4，'{}' 不是 IPv4 地址", ip.trim()));
+    }
+    let cgnat = ipv4_to_u32(&Ipv4Addr::new(100, 64, 0, 0)) as u128;
+    Ok(cidr_bounds(addr, 10, 32).0 == cgnat)
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UlaParts {
+    pub global_id: String, // 40 位全局 ID，10 位十六进制
+    pub subnet_id: String, // 16 位子网 ID，4 位十六进制
+    pub is_locally_assigned: bool, // L 位，fd00::/8 为本地分配
+}
+
+/// 拆解 fc00::/7 唯一本地地址（RFC 4193）的各组成部分
+#[tauri::command]
+pub fn ula_breakdown(ip: String) -> Result<UlaParts, String> {
+    let ipv6 = Ipv6Addr::from_str(ip.trim()).map_err(|e| format!("无效的 IPv6 地址 '{}': {}", ip, e))?;
+    let num = ipv6_to_u128(&ipv6);
+    if num >> 121 != 0x7E {
+        return Err(format!("'{}' 不是 ULA 地址（fc00::/7）", ipv6));
+    }
+    Ok(UlaParts {
+        global_id: format!("{:010x}", (num >> 80) & 0xFF_FFFF_FFFF),
+        subnet_id: format!("{:04x}", (num >> 64) & 0xFFFF),
+        is_locally_assigned: (num >> 120) & 1 == 1,
+    })
+}
+
+/// 两个地址落入不同网络的最短掩码长度，即公共前缀长度 + 1
+#[tauri::command]
+pub fn separating_prefix(a: String, b: String) -> Result<u8, String> {
+    let (a_int, a_bits) = parse_ip(&a)?;
+    let (b_int, b_bits) = parse_ip(&b)?;
+    if a_bits != b_bits {
+        return Err(format!("地址 '{}' 与 '{}' 的地址族不一致", a.trim(), b.trim()));
+    }
+    if a_int == b_int {
+        return Err(format!("地址 '{}' 与 '{}' 相同，任何掩码下都在同一网络", a.trim(), b.trim()));
+    }
+    Ok(common_prefix_len(a_int, b_int, a_bits) + 1)
+}
+
+#[tauri::command]
+pub fn halve(cidr: String) -> Result<(String, String), String> {
+    let (addr, prefix, bits) = parse_cidr(&cidr)?;
+    if prefix == bits {
+        return Err(format!("CIDR '{}' 已是单地址块，无法再拆分", cidr.trim()));
+    }
+    let (network, _) = cidr_bounds(addr, prefix, bits);
+    let upper = network | (1u128 << (bits - prefix - 1));
+    Ok((
+        format!("{}/{}", format_addr(network, bits), prefix + 1),
+        format!("{}/{}", format_addr(upper, bits), prefix + 1),
+    ))
+}
+
+#[tauri::command]
+pub fn is_cidr(input: String) -> bool {
+    matches!(
+        IpTranslationType::from_str(&input),
+        IpTranslationType::Ipv4Mask | IpTranslationType::Ipv6Mask
+    )
+}
+
+/// 两个同族范围并集中不重复的地址数
+#[tauri::command]
+pub fn union_size(a: String, b: String) -> Result<String, String> {
+    let (a_start, a_end, a_bits) = parse_range(&a)?;
+    let (b_start, b_end, b_bits) = parse_range(&b)?;
+    if a_bits != b_bits {
+        return Err(format!("范围 '{}' 与 '{}' 的地址族不一致", a.trim(), b.trim()));
+    }
+    let merged = merge_intervals(vec![(a_start, a_end), (b_start, b_end)]);
+    Ok(intervals_size_string(&merged))
+}
+
+/// IPv6 主机偏移占块大小的比例：偏移 / 2^主机位数。
+/// 主机位较多时 f64 只保留约 15 位有效数字，仅适合可视化展示
+#[tauri::command]
+pub fn ipv6_host_fraction(cidr_host: String) -> Result<f64, String> {
+    let (addr, prefix, bits) = parse_cidr(&cidr_host)?;
+    if bits != 128 {
+        return Err(format!("'{}' 不是 IPv6 CIDR", cidr_host.trim()));
+    }
+    let (network, _) = cidr_bounds(addr, prefix, bits);
+    Ok((addr - network) as f64 / 2f64.powi((bits - prefix) as i32))
+}
+
+/// Cisco 风格通配符匹配，输入如 "10.0.0.0 0.0.255.0"（也接受 "/" 分隔），
+/// 通配符中为 1 的位不参与比较，支持不连续的通配符
+#[tauri::command]
+pub fn wildcard_matches(host_with_wildcard: String, test_ip: String) -> Result<bool, String> {
+    let parts: Vec<&str> = host_with_wildcard
+        .split(|c: char| c.is_whitespace() || c == '/')
+        .filter(|part| !part.is_empty())
+        .collect();
+    if parts.len() != 2 {
+        return Err(format!("无效的通配符格式 '{}'，应为 \"地址 通配符\"", host_with_wildcard));
+    }
+
+    let host = Ipv4Addr::from_str(parts[0]).map_err(|e| format!("无效的 IPv4 地址 '{}': {}", parts[0], e))?;
+    let wildcard = Ipv4Addr::from_str(parts[1]).map_err(|e| format!("无效的通配符 '{}': {}", parts[1], e))?;
+    let test = Ipv4Addr::from_str(test_ip.trim()).map_err(|e| format!("无效的 IPv4 地址 '{}': {}", test_ip, e))?;
+
+    let care = !ipv4_to_u32(&wildcard);
+    Ok((ipv4_to_u32(&host) ^ ipv4_to_u32(&test)) & care == 0)
+}
+
+/// 生成 Cisco 风格的前缀列表，序号按设备默认步长 5 递增，IPv6 使用 "ipv6 prefix-list"
+#[tauri::command]
+pub fn prefix_list(cidrs: Vec<String>, name: String) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return Err(format!("无效的前缀列表名称 '{}'", name));
+    }
+
+    let mut lines = Vec::new();
+    for (i, cidr) in cidrs.iter().enumerate() {
+        let (addr, prefix, bits) = parse_cidr(cidr)?;
+        let (network, _) = cidr_bounds(addr, prefix, bits);
+        let keyword = if bits == 32 { "ip" } else { "ipv6" };
+        lines.push(format!(
+            "{} prefix-list {} seq {} permit {}/{}",
+            keyword,
+            name,
+            (i + 1) * 5,
+            format_addr(network, bits),
+            prefix
+        ));
+    }
+    Ok(lines.join("\n"))
+}
+
+/// IPv6 组播地址的作用域（RFC 4291 / RFC 7346），取第二个字节的低 4 位
+#[tauri::command]
+pub fn multicast_scope(ip: String) -> Result<String, String> {
+    let ipv6 = Ipv6Addr::from_str(ip.trim()).map_err(|e| format!("无效的 IPv6 地址 '{}': {}", ip, e))?;
+    if !ipv6.is_multicast() {
+        return Err(format!("'{}' 不是 IPv6 组播地址（ff00::/8）", ipv6));
+    }
+    let scope = match ipv6.octets()[1] & 0x0F {
+        0x1 => "interface-local",
+        0x2 => "link-local",
+        0x3 => "realm-local",
+        0x4 => "admin-local",
+        0x5 => "site-local",
+        0x8 => "organization-local",
+        0xE => "global",
+        0x0 | 0xF => "reserved",
+        _ => "unassigned",
+    };
+    Ok(scope.into())
+}
+
+/// 常用的 IPv4/IPv6 知名组播组 (地址, 名称)
+const MULTICAST_GROUPS: &[(&str, &str)] = &[
+    ("224.0.0.1", "all-hosts"),
+    ("224.0.0.2", "all-routers"),
+    ("224.0.0.5", "ospf-all-routers"),
+    ("224.0.0.6", "ospf-designated-routers"),
+    ("224.0.0.9", "rip2-routers"),
+    ("224.0.0.10", "eigrp-routers"),
+    ("224.0.0.13", "pim-routers"),
+    ("224.0.0.18", "vrrp"),
+    ("224.0.0.22", "igmpv3-reports"),
+    ("224.0.0.251", "mdns"),
+    ("224.0.0.252", "llmnr"),
+    ("224.0.1.1", "ntp"),
+    ("239.255.255.250", "ssdp"),
+    ("ff01::1", "all-nodes (interface-local)"),
+    ("ff01::2", "all-routers (interface-local)"),
+    ("ff02::1", "all-nodes"),
+    ("ff02::2", "all-routers"),
+    ("ff02::5", "ospfv3-all-routers"),
+    ("ff02::6", "ospfv3-designated-routers"),
+    ("ff02::9", "rip-routers"),
+    ("ff02::a", "eigrp-routers"),
+    ("ff02::c", "ssdp"),
+    ("ff02::d", "pim-routers"),
+    ("ff02::12", "vrrp"),
+    ("ff02::16", "mldv2-reports"),
+    ("ff02::fb", "mdns"),
+    ("ff02::1:2", "dhcpv6-all-relay-agents-and-servers"),
+    ("ff02::1:3", "llmnr"),
+    ("ff05::2", "all-routers (site-local)"),
+    ("ff05::1:3", "dhcpv6-all-servers"),
+    ("ff05::101", "ntp"),
+];
+
+/// 按名称或地址（不区分大小写的子串匹配）查找知名组播组，空查询返回全部
+#[tauri::command]
+pub fn multicast_lookup(query: String) -> Result<Vec<(String, String)>, String> {
+    let query = query.trim().to_ascii_lowercase();
+    Ok(MULTICAST_GROUPS
+        .iter()
+        .filter(|(address, name)| address.contains(&query) || name.contains(&query))
+        .map(|&(address, name)| (address.to_string(), name.to_string()))
+        .collect())
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AggSavings {
+    pub before: usize,
+    pub after: usize,
+    pub reduced_by: usize,
+}
+
+#[tauri::command]
+pub fn aggregation_savings(cidrs: Vec<String>) -> Result<AggSavings, String> {
+    let after = aggregate_cidr_list(&cidrs)?.len();
+    Ok(AggSavings {
+        before: cidrs.len(),
+        after,
+        reduced_by: cidrs.len().saturating_sub(after),
+    })
+}
+
+/// 把单个词中的地址/CIDR 部分替换为规范形式，前后的标点原样保留，无法识别时原样返回
+fn canonicalize_token(token: &str) -> String {
+    let is_addr_char = |c: char| c.is_ascii_hexdigit() || c == ':' || c == '.' || c == '/';
+    let (Some(core_start), Some(core_last)) = (token.find(is_addr_char), token.rfind(is_addr_char)) else {
+        return token.to_string();
+    };
+    // 句末的 "." 不属于地址
+    let core = token[core_start..=core_last].trim_end_matches('.');
+    let core_end = core_start + core.len();
+
+    let canonical = if let Ok((addr, bits)) = parse_ip(core) {
+        format_addr(addr, bits)
+    } else if let Ok((addr, prefix, bits)) = parse_cidr(core) {
+        format!("{}/{}", format_addr(addr, bits), prefix)
+    } else {
+        return token.to_string();
+    };
+    format!("{}{}{}", &token[..core_start], canonical, &token[core_end..])
+}
+
+/// 逐行扫描文本，把可识别的 IP/CIDR 替换为规范形式，其余文本（包括空白）保持不变
+#[tauri::command]
+pub fn canonicalize_blob(text: String) -> Result<String, String> {
+    let mut output = String::with_capacity(text.len());
+    let mut token = String::new();
+    for c in text.chars() {
+        if c.is_whitespace() {
+            output.push_str(&canonicalize_token(&token));
+            token.clear();
+            output.push(c);
+        } else {
+            token.push(c);
+        }
+    }
+    output.push_str(&canonicalize_token(&token));
+    Ok(output)
+}
+
+/// 与 IPv4 范围有交集的每个 /24，附带是否被范围完整覆盖
+#[tauri::command]
+pub fn blackhole_slash24s(range: String) -> Result<Vec<(String, bool)>, String> {
+    let (start, end) = parse_ipv4_range(&range)?;
+    Ok(((start >> 8)..=(end >> 8))
+        .map(|block| {
+            let network = block << 8;
+            let full = start <= network && network | 0xFF <= end;
+            (format!("{}/24", u32_to_ipv4(network)), full)
+        })
+        .collect())
+}
+
+/// 地址整数值的高、低 64 位无符号数（十进制字符串），IPv4 的高 64 位恒为 0
+#[tauri::command]
+pub fn to_u64_pair(ip: String) -> Result<(String, String), String> {
+    let (num, _) = parse_ip(&ip)?;
+    Ok((((num >> 64) as u64).to_string(), (num as u64).to_string()))
+}
+
+/// 由高、低 64 位无符号数还原 IPv6 地址
+#[tauri::command]
+pub fn from_u64_pair(high: String, low: String) -> Result<String, String> {
+    let high: u64 = high
+        .trim()
+        .parse()
+        .map_err(|e| format!("无效的高 64 位数值 '{}': {}", high, e))?;
+    let low: u64 = low
+        .trim()
+        .parse()
+        .map_err(|e| format!("无效的低 64 位数值 '{}': {}", low, e))?;
+    Ok(u128_to_ipv6(((high as u128) << 64) | low as u128).to_string())
+}
+
+/// 新增 CIDR 前检查是否与已有块冲突，返回第一个冲突的已有块；不同地址族的块互不冲突
+#[tauri::command]
+pub fn would_overlap(existing: Vec<String>, candidate: String) -> Result<Option<String>, String> {
+    let (addr, prefix, bits) = parse_cidr(&candidate)?;
+    let candidate_bounds = cidr_bounds(addr, prefix, bits);
+    for cidr in &existing {
+        let (e_addr, e_prefix, e_bits) = parse_cidr(cidr)?;
+        if e_bits == bits && intervals_overlap(cidr_bounds(e_addr, e_prefix, e_bits), candidate_bounds) {
+            return Ok(Some(cidr.trim().to_string()));
+        }
+    }
+    Ok(None)
+}
+
+/// 块内指定百分比位置的地址：start + round(percent / 100 * (size - 1))，percent 截断到 [0, 100]
+#[tauri::command]
+pub fn address_at_percent(block: String, percent: f64) -> Result<String, String> {
+    if percent.is_nan() {
+        return Err("百分比不能为 NaN".into());
+    }
+    let (start, end, bits) = parse_block(&block)?;
+    let span = end - start;
+    let offset = ((percent.clamp(0.0, 100.0) / 100.0) * span as f64).round() as u128;
+    Ok(format_addr(start + offset.min(span), bits))
+}
+
+#[tauri::command]
+pub fn from_octets(a: u16, b: u16, c: u16, d: u16) -> Result<String, String> {
+    let mut octets = [0u8; 4];
+    for (i, &value) in [a, b, c, d].iter().enumerate() {
+        octets[i] = u8::try_from(value).map_err(|_| format!("第 {} 段 '{}' 超出 0~255 范围", i + 1, value))?;
+    }
+    Ok(Ipv4Addr::from(octets).to_string())
+}
+
+#[tauri::command]
+pub fn from_segments(segments: Vec<u16>) -> Result<String, String> {
+    let segments: [u16; 8] = segments
+        .try_into()
+        .map_err(|s: Vec<u16>| format!("IPv6 需要 8 段，实际为 {} 段", s.len()))?;
+    Ok(Ipv6Addr::from(segments).to_string())
+}
+
+/// 能容纳 host_count 个主机的最小块对应的掩码，格式为 "/N"；IPv4 按可用主机数计算
+#[tauri::command]
+pub fn nearest_prefix_label(host_count: u64, family: String) -> Result<String, String> {
+    let bits = parse_family(&family)?;
+    smallest_prefix_for_hosts(host_count, bits)
+        .map(|prefix| format!("/{}", prefix))
+        .ok_or_else(|| format!("主机数 {} 超出 {} 地址空间", host_count, family.trim()))
+}
+
+/// RFC 2317 无类反向解析委派的区名，如 10.0.0.0/26 -> "0-63.0.0.10.in-addr.arpa"，
+/// 仅适用于 /25 ~ /31 的 IPv4 前缀
+#[tauri::command]
+pub fn rfc2317_zone(cidr: String) -> Result<String, String> {
+    let (addr, prefix, bits) = parse_cidr(&cidr)?;
+    if bits != 32 {
+        return Err(format!("'{}' 不是 IPv4 CIDR，RFC 2317 仅适用于 IPv4", cidr.trim()));
+    }
+    if !(25..=31).contains(&prefix) {
+        return Err(format!("RFC 2317 仅适用于 /25 ~ /31 前缀，'{}' 的掩码为 /{}", cidr.trim(), prefix));
+    }
+    let (network, broadcast) = cidr_bounds(addr, prefix, bits);
+    let octets = u32_to_ipv4(network as u32).octets();
+    Ok(format!(
+        "{}-{}.{}.{}.{}.in-addr.arpa",
+        octets[3],
+        broadcast & 0xFF,
+        octets[2],
+        octets[1],
+        octets[0]
+    ))
+}
+
+/// 标记规范压缩形式中被 "::" 省略的段（共 8 个元素）
+#[tauri::command]
+pub fn ipv6_compression_map(ip: String) -> Result<Vec<bool>, String> {
+    let ipv6 = Ipv6Addr::from_str(ip.trim()).map_err(|e| format!("无效的 IPv6 地址 '{}': {}", ip, e))?;
+    let compressed = ipv6.to_string();
+    let Some((left, right)) = compressed.split_once("::") else {
+        return Ok(vec![false; 8]);
+    };
+    let first = ipv6_explicit_groups(left);
+    let last = 8 - ipv6_explicit_groups(right);
+    Ok((0..8).map(|i| i >= first && i < last).collect())
+}
+
+/// 单次枚举子网的数量上限，结果一次性生成，过大的 limit 会耗尽内存
+const MAX_SUBNET_PAGE: u64 = 65536;
+
+/// 分页枚举 CIDR 按 new_prefix 划分出的子网，返回第 [offset, offset + limit) 个，limit 不超过 MAX_SUBNET_PAGE
+#[tauri::command]
+pub fn enumerate_subnets_page(cidr: String, new_prefix: u8, offset: u64, limit: u64) -> Result<Vec<String>, String> {
+    if limit > MAX_SUBNET_PAGE {
+        return Err(format!("每页数量 {} 超出上限 {}", limit, MAX_SUBNET_PAGE));
+    }
+    let (addr, prefix, bits) = parse_cidr(&cidr)?;
+    if new_prefix < prefix || new_prefix > bits {
+        return Err(format!("新掩码长度 '{}' 必须在 {} ~ {} 之间", new_prefix, prefix, bits));
+    }
+    let (network, _) = cidr_bounds(addr, prefix, bits);
+
+    // 子网总数 2^(new_prefix - prefix)，超过 u64 时 offset 不可能越界
+    let total = host_span(new_prefix - prefix);
+    if offset as u128 > total {
+        return Err(format!("偏移量 {} 超出子网总数 {}", offset, total + 1));
+    }
+    // 拆到 /128 时 total 为 u128::MAX，剩余数量加 1 会溢出
+    let count = (limit as u128).min((total - offset as u128).saturating_add(1));
+
+    let child_bits = bits - new_prefix;
+    Ok((offset as u128..offset as u128 + count)
+        // child_bits 为 128 时（::/0 不拆分）只有第 0 个子网，移位越界即偏移 0
+        .map(|i| i.checked_shl(child_bits as u32).unwrap_or(0))
+        .map(|step| format!("{}/{}", format_addr(network + step, bits), new_prefix))
+        .collect())
+}
+
+/// 地址在 CIDR 内以中点为轴的镜像地址：broadcast - (ip - network)
+#[tauri::command]
+pub fn reflect_in_cidr(ip: String, cidr: String) -> Result<String, String> {
+    let (ip_int, ip_bits) = parse_ip(&ip)?;
+    let (addr, prefix, bits) = parse_cidr(&cidr)?;
+    let (network, broadcast) = cidr_bounds(addr, prefix, bits);
+    if ip_bits != bits || ip_int < network || ip_int > broadcast {
+        return Err(format!("地址 '{}' 不在 '{}' 内", ip.trim(), cidr.trim()));
+    }
+    Ok(format_addr(broadcast - (ip_int - network), bits))
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AggregateInfo {
+    pub cidr: String,
+    pub covered_addresses: String,
+    pub requested_addresses: String,
+    pub efficiency_percent: f64, // 请求地址数 / 覆盖地址数 * 100
+}
+
+/// 用单个 CIDR 汇总整个列表，并给出覆盖效率
+#[tauri::command]
+pub fn single_aggregate(cidrs: Vec<String>) -> Result<AggregateInfo, String> {
+    let (intervals, family) = parse_cidr_list(&cidrs)?;
+    let (Some(bits), Some(first), Some(last)) = (family, intervals.first(), intervals.last()) else {
+        return Err("CIDR 列表不能为空".into());
+    };
+    let (network, prefix) = covering_cidr(first.0, last.1, bits);
+
+    let requested: f64 = intervals.iter().map(|&(start, end)| (end - start) as f64 + 1.0).sum();
+    let covered = 2f64.powi((bits - prefix) as i32);
+    Ok(AggregateInfo {
+        cidr: format!("{}/{}", format_addr(network, bits), prefix),
+        covered_addresses: block_size_string(bits - prefix),
+        requested_addresses: intervals_size_string(&intervals),
+        efficiency_percent: requested / covered * 100.0,
+    })
+}
+
+/// 按顺序匹配 (动作, CIDR) 规则，返回第一条命中规则的动作，均未命中时为 "default-deny"；
+/// 规则会先全部校验，动作只能是 allow 或 deny，不同地址族的规则视为不命中
+#[tauri::command]
+pub fn evaluate_policy(ip: String, rules: Vec<(String, String)>) -> Result<String, String> {
+    let (ip_int, ip_bits) = parse_ip(&ip)?;
+
+    let mut parsed = Vec::with_capacity(rules.len());
+    for (action, cidr) in &rules {
+        let action = action.trim().to_ascii_lowercase();
+        if action != "allow" && action != "deny" {
+            return Err(format!("无效的规则动作 '{}'，应为 allow 或 deny", action));
+        }
+        let (addr, prefix, bits) = parse_cidr(cidr)?;
+        parsed.push((action, bits, cidr_bounds(addr, prefix, bits)));
+    }
+
+    Ok(parsed
+        .into_iter()
+        .find(|&(_, bits, (start, end))| bits == ip_bits && start <= ip_int && ip_int <= end)
+        .map(|(action, _, _)| action)
+        .unwrap_or_else(|| "default-deny".into()))
+}
+
+/// 按 ipcalc --json 的字段名输出子网信息，值均为字符串；
+/// IPv4 /31、/32 及 IPv6 不输出 BROADCAST，主机范围取整个块
+#[tauri::command]
+pub fn ipcalc_json(cidr: String) -> Result<serde_json::Value, String> {
+    let (addr, prefix, bits) = parse_cidr(&cidr)?;
+    let (network, broadcast) = cidr_bounds(addr, prefix, bits);
+    let has_broadcast = bits == 32 && prefix < 31;
+    let (host_min, host_max) = if has_broadcast {
+        (network + 1, broadcast - 1)
+    } else {
+        (network, broadcast)
+    };
+    let hosts = if bits == 32 {
+        ipv4_usable_hosts(prefix).to_string()
+    } else {
+        block_size_string(bits - prefix)
+    };
+
+    let mut fields = serde_json::Map::new();
+    let mut put = |key: &str, value: String| {
+        fields.insert(key.to_string(), serde_json::Value::String(value));
+    };
+    put("ADDRESS", format_addr(addr, bits));
+    put("NETMASK", format_addr(prefix_mask(prefix, bits), bits));
+    put("PREFIX", prefix.to_string());
+    put("NETWORK", format_addr(network, bits));
+    if has_broadcast {
+        put("BROADCAST", format_addr(broadcast, bits));
+    }
+    put("HOSTMIN", format_addr(host_min, bits));
+    put("HOSTMAX", format_addr(host_max, bits));
+    put("HOSTS", hosts);
+    Ok(serde_json::Value::Object(fields))
+}
+
+/// 地址的分片桶序号，折叠方式固定不变以保证跨版本稳定：
+/// 1. 取地址整数值 n（u128，IPv4 为 0 ~ 2^32-1）
+/// 2. x = (n >> 64) ^ (n & 0xFFFF_FFFF_FFFF_FFFF)，得到 64 位
+/// 3. y = (x >> 32) ^ (x & 0xFFFF_FFFF)，得到 32 位
+/// 4. 桶序号 = y % buckets
+#[tauri::command]
+pub fn shard_bucket(ip: String, buckets: u32) -> Result<u32, String> {
+    if buckets == 0 {
+        return Err("桶数量必须大于 0".into());
+    }
+    let (num, _) = parse_ip(&ip)?;
+    let folded64 = ((num >> 64) as u64) ^ (num as u64);
+    let folded32 = ((folded64 >> 32) as u32) ^ (folded64 as u32);
+    Ok(folded32 % buckets)
+}
+
+/// 只返回调用方指定的结果字段（camelCase 字段名），未知字段名忽略，用于减小 IPC 负载
+#[tauri::command]
+pub fn translate_ip_fields(
+    ip: String,
+    fields: Vec<String>,
+) -> Result<serde_json::Map<String, serde_json::Value>, String> {
+    let result = translate_ip(ip)?;
+    let serde_json::Value::Object(all) =
+        serde_json::to_value(&result).map_err(|e| format!("序列化结果失败: {}", e))?
+    else {
+        return Err("序列化结果不是对象".into());
+    };
+    Ok(all
+        .into_iter()
+        .filter(|(key, _)| fields.contains(key))
+        .collect())
+}
+
+/// 范围精确拆分的 CIDR，按块从大到小（掩码从短到长）排序，同样大小按地址顺序
+#[tauri::command]
+pub fn range_to_cidrs_sorted(range: String) -> Result<Vec<String>, String> {
+    let (start, end, bits) = parse_range(&range)?;
+    let mut blocks = range_to_blocks(start, end, bits);
+    blocks.sort_by_key(|&(network, prefix)| (prefix, network));
+    Ok(blocks
+        .into_iter()
+        .map(|(network, prefix)| format!("{}/{}", format_addr(network, bits), prefix))
+        .collect())
+}
+
+/// 用单个最小 CIDR 表示范围时多包含的地址数
+#[tauri::command]
+pub fn cidr_representation_waste(range: String) -> Result<String, String> {
+    let (start, end, bits) = parse_range(&range)?;
+    let (_, prefix) = covering_cidr(start, end, bits);
+    // (块大小 - 1) - (范围大小 - 1)，避免整个 IPv6 空间时溢出
+    Ok((host_span(bits - prefix) - (end - start)).to_string())
+}
+
+/// 分配方案的稳定指纹：CIDR 规范化为网络地址、去重并按 (地址族, 网络地址, 掩码) 排序，
+/// 以 "," 连接后计算 64 位 FNV-1a 哈希，输出 16 位十六进制。
+/// 不使用 std 的 DefaultHasher，因为它不保证跨版本稳定
+#[tauri::command]
+pub fn plan_fingerprint(cidrs: Vec<String>) -> Result<String, String> {
+    let mut blocks = Vec::with_capacity(cidrs.len());
+    for cidr in &cidrs {
+        let (addr, prefix, bits) = parse_cidr(cidr)?;
+        blocks.push((bits, cidr_bounds(addr, prefix, bits).0, prefix));
+    }
+    blocks.sort();
+    blocks.dedup();
+
+    let canonical = blocks
+        .iter()
+        .map(|&(bits, network, prefix)| format!("{}/{}", format_addr(network, bits), prefix))
+        .collect::<Vec<String>>()
+        .join(",");
+    let hash = canonical.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    Ok(format!("{:016x}", hash))
+}
+
+/// 包含指定主机、且可用主机数不少于 usable 的最小 CIDR
+#[tauri::command]
+pub fn prefix_around_host(ip: String, usable: u64) -> Result<String, String> {
+    let (addr, bits) = parse_ip(&ip)?;
+    let prefix = smallest_prefix_for_hosts(usable, bits)
+        .ok_or_else(|| format!("可用主机数 {} 超出地址空间", usable))?;
+    let (network, _) = cidr_bounds(addr, prefix, bits);
+    Ok(format!("{}/{}", format_addr(network, bits), prefix))
+}
+
+/// 从 ISATAP 地址（RFC 5214）的接口标识 [0|200]:5efe:a.b.c.d 中取出嵌入的 IPv4
+#[tauri::command]
+pub fn isatap_extract(ip: String) -> Result<String, String> {
+    let ipv6 = Ipv6Addr::from_str(ip.trim()).map_err(|e| format!("无效的 IPv6 地址 '{}': {}", ip, e))?;
+    let num = ipv6_to_u128(&ipv6);
+    // 接口标识高 32 位为 0000:5efe，忽略 u/l 位（0200:5efe 表示全局唯一的 IPv4）
+    if (num >> 32) as u32 & 0xFDFF_FFFF != 0x0000_5EFE {
+        return Err(format!("'{}' 不是 ISATAP 地址（接口标识应为 ::5efe:a.b.c.d）", ipv6));
+    }
+    Ok(u32_to_ipv4(num as u32).to_string())
+}
+
+/// 在 /64 前缀下以接口标识 0:5efe:V4 构造 ISATAP 地址（RFC 5214）
+#[tauri::command]
+pub fn isatap_build(prefix: String, ipv4: String) -> Result<String, String> {
+    let (addr, prefix_len, bits) = parse_cidr(&prefix)?;
+    if bits != 128 || prefix_len != 64 {
+        return Err(format!("ISATAP 前缀 '{}' 必须是 IPv6 /64", prefix.trim()));
+    }
+    let v4 = Ipv4Addr::from_str(ipv4.trim()).map_err(|e| format!("无效的 IPv4 地址 '{}': {}", ipv4, e))?;
+    let (network, _) = cidr_bounds(addr, prefix_len, bits);
+    Ok(u128_to_ipv6(network | (0x5EFE_u128 << 32) | ipv4_to_u32(&v4) as u128).to_string())
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AggregateReport {
+    pub aggregate: String,
+    pub contained: Vec<String>,
+    pub outside: Vec<String>,
+}
+
+/// 计算覆盖整个列表的单个汇总 CIDR，并逐项报告是否落在汇总内；与其他列表命令一样要求同一地址族
+#[tauri::command]
+pub fn aggregate_with_report(cidrs: Vec<String>) -> Result<AggregateReport, String> {
+    let (intervals, family) = parse_cidr_list(&cidrs)?;
+    let (Some(bits), Some(first), Some(last)) = (family, intervals.first(), intervals.last()) else {
+        return Err("CIDR 列表不能为空".into());
+    };
+    let (network, prefix) = covering_cidr(first.0, last.1, bits);
+    let aggregate = cidr_bounds(network, prefix, bits);
+
+    let mut report = AggregateReport {
+        aggregate: format!("{}/{}", format_addr(network, bits), prefix),
+        contained: Vec::new(),
+        outside: Vec::new(),
+    };
+    for cidr in &cidrs {
+        let (addr, prefix, _) = parse_cidr(cidr)?;
+        let (start, end) = cidr_bounds(addr, prefix, bits);
+        if aggregate.0 <= start && end <= aggregate.1 {
+            report.contained.push(cidr.trim().to_string());
+        } else {
+            report.outside.push(cidr.trim().to_string());
+        }
+    }
+    Ok(report)
+}
+
+/// 按地址整数值最低位返回 "even" 或 "odd"
+#[tauri::command]
+pub fn parity(ip: String) -> Result<String, String> {
+    let (num, _) = parse_ip(&ip)?;
+    Ok(if num & 1 == 0 { "even" } else { "odd" }.into())
+}
+
+/// 把扫描得到的地址列表合并成 CIDR，生成 "object-group network" 配置片段
+#[tauri::command]
+pub fn summarize_object_group(ips: Vec<String>, group_name: String) -> Result<String, String> {
+    let name = group_name.trim();
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return Err(format!("无效的对象组名称 '{}'", group_name));
+    }
+    let mut lines = vec![format!("object-group network {}", name)];
+    lines.extend(
+        ips_to_cidrs(&ips)?
+            .into_iter()
+            .map(|cidr| format!(" network-object {}", cidr)),
+    );
+    Ok(lines.join("\n"))
+}
+
+/// 按 /24 推断地址在网段中的角色："network"、"broadcast"、"first-usable"、"last-usable" 或 "host"
+#[tauri::command]
+pub fn usable_role_slash24(ip: String) -> Result<String, String> {
+    let ipv4 = Ipv4Addr::from_str(ip.trim()).map_err(|e| format!("无效的 IPv4 地址 '{}': {}", ip, e))?;
+    let role = match ipv4.octets()[3] {
+        0 => "network",
+        255 => "broadcast",
+        1 => "first-usable",
+        254 => "last-usable",
+        _ => "host",
+    };
+    Ok(role.into())
+}
+
+/// 从父网段中排除多个子网段（必须都在父网段内），返回剩余部分的最少 CIDR 集合
+#[tauri::command]
+pub fn cidr_exclude_many(parent: String, excluded: Vec<String>) -> Result<Vec<String>, String> {
+    let (addr, prefix, bits) = parse_cidr(&parent)?;
+    let parent_bounds = cidr_bounds(addr, prefix, bits);
+    let mut holes = Vec::with_capacity(excluded.len());
+    for cidr in &excluded {
+        let (ex_addr, ex_prefix, ex_bits) = parse_cidr(cidr)?;
+        let (start, end) = cidr_bounds(ex_addr, ex_prefix, ex_bits);
+        if ex_bits != bits || start < parent_bounds.0 || end > parent_bounds.1 {
+            return Err(format!("排除网段 '{}' 不在父网段 '{}' 内", cidr, parent));
+        }
+        holes.push((start, end));
+    }
+    let remaining = subtract_intervals(&[parent_bounds], &merge_intervals(holes));
+    Ok(intervals_to_cidrs(&remaining, bits))
+}
+
+/// 从起始地址开始连续取 n 个地址，超出地址空间时报错
+#[tauri::command]
+pub fn next_n_addresses(start: String, n: u32) -> Result<Vec<String>, String> {
+    let (num, bits) = parse_ip(&start)?;
+    if n == 0 {
+        return Ok(Vec::new());
+    }
+    if host_span(bits) - num < (n - 1) as u128 {
+        return Err(format!("从 '{}' 起取 {} 个地址会超出地址空间", start.trim(), n));
+    }
+    Ok((0..n as u128).map(|i| format_addr(num + i, bits)).collect())
+}
+
+/// 是否为主机路由（IPv4 /32 或 IPv6 /128）
+#[tauri::command]
+pub fn is_host_route(cidr: String) -> Result<bool, String> {
+    let (_, prefix, bits) = parse_cidr(&cidr)?;
+    Ok(prefix == bits)
+}
+
+/// 由 MAC 地址按 EUI-64 规则（中间插入 fffe、翻转 U/L 位）生成 fe80:: 链路本地地址
+#[tauri::command]
+pub fn mac_to_link_local(mac: String) -> Result<String, String> {
+    let trimmed = mac.trim();
+    let separator = if trimmed.contains('-') { '-' } else { ':' };
+    let octets = trimmed
+        .split(separator)
+        .map(|part| {
+            // from_str_radix 接受 "+0" 这类带符号写法，先确认两位都是十六进制数字
+            if part.len() == 2 && part.chars().all(|c| c.is_ascii_hexdigit()) {
+                u8::from_str_radix(part, 16).ok()
+            } else {
+                None
+            }
+        })
+        .collect::<Option<Vec<u8>>>()
+        .filter(|octets| octets.len() == 6)
+        .ok_or_else(|| format!("无效的 MAC 地址 '{}'，应为 6 组以 ':' 或 '-' 分隔的两位十六进制数", mac))?;
+
+    let eui64 = [
+        octets[0] ^ 0x02, octets[1], octets[2], 0xff, 0xfe, octets[3], octets[4], octets[5],
+    ];
+    let interface_id = eui64.iter().fold(0u128, |acc, &b| (acc << 8) | b as u128);
+    Ok(u128_to_ipv6((0xfe80u128 << 112) | interface_id).to_string())
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RangeCidrStats {
+    pub total_ranges: usize,
+    pub total_cidrs: usize,
+    pub total_addresses: String, // 各范围地址数之和，重叠部分重复计数
+}
+
+/// 批量统计范围转 CIDR 所需的块数，衡量转换效率
+#[tauri::command]
+pub fn range_to_cidr_stats(ranges: Vec<String>) -> Result<RangeCidrStats, String> {
+    let mut total_cidrs = 0;
+    let mut spans = Vec::with_capacity(ranges.len());
+    for range in &ranges {
+        let (start, end, bits) = parse_range(range)?;
+        total_cidrs += range_to_blocks(start, end, bits).len();
+        spans.push((start, end));
+    }
+    Ok(RangeCidrStats {
+        total_ranges: ranges.len(),
+        total_cidrs,
+        total_addresses: intervals_size_string(&spans),
+    })
+}
+
+/// 把范围精确拆分成最少的 CIDR 块，每一步取对齐且能放下的最大块，支持 IPv4/IPv6
+#[tauri::command]
+pub fn range_to_cidrs(range: String) -> Result<Vec<String>, String> {
+    let (start, end, bits) = parse_range(&range)?;
+    Ok(intervals_to_cidrs(&[(start, end)], bits))
+}
+
+/// 单个地址文本无法解析的原因
+fn explain_bad_address(input: &str) -> &'static str {
+    if input.is_empty() {
+        return "empty address";
+    }
+    if input.contains(':') {
+        if input.matches("::").count() > 1 {
+            return "multiple '::'";
+        }
+        let colons = input.matches(':').count();
+        if colons > 8 || (colons > 7 && !input.contains("::")) {
+            return "too many colons";
+        }
+        if ipv6_explicit_groups(input) > 8 {
+            return "too many groups";
+        }
+        let groups: Vec<&str> = input.split(':').collect();
+        if groups.iter().any(|g| !g.contains('.') && !g.chars().all(|c| c.is_ascii_hexdigit())) {
+            return "invalid hex digit";
+        }
+        if groups.iter().any(|g| !g.contains('.') && g.len() > 4) {
+            return "group too long";
+        }
+        return "malformed IPv6 address";
+    }
+    if input.contains('.') {
+        let octets: Vec<&str> = input.split('.').collect();
+        if octets.len() != 4 {
+            return "wrong number of octets";
+        }
+        if octets.iter().any(|o| o.is_empty() || !o.chars().all(|c| c.is_ascii_digit())) {
+            return "not numeric";
+        }
+        if octets.iter().any(|o| o.len() > 3 || o.parse::<u16>().map_or(true, |v| v > 255)) {
+            return "octet out of range";
+        }
+        if octets.iter().any(|o| o.len() > 1 && o.starts_with('0')) {
+            return "leading zero in octet";
+        }
+        return "malformed IPv4 address";
+    }
+    if input.chars().all(|c| c.is_ascii_digit()) {
+        return "number too large";
+    }
+    "not numeric"
+}
+
+/// 输入被识别为 UnknownIp 时给出原因；能识别时返回错误
+#[tauri::command]
+pub fn explain_unknown(input: String) -> Result<String, String> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Ok("empty input".into());
+    }
+    let ip_type = IpTranslationType::from_str(trimmed);
+    if !matches!(ip_type, IpTranslationType::UnknownIp) {
+        return Err(format!("'{}' 可以识别，类型为 {:?}", trimmed, ip_type));
+    }
+
+    let reason = if let Some((start, end)) = trimmed.split_once('-') {
+        let (start, end) = (start.trim(), end.trim());
+        match (parse_ip(start), parse_ip(end)) {
+            (Ok((_, a)), Ok((_, b))) if a != b => "range endpoints differ in address family",
+            (Err(_), _) => explain_bad_address(start),
+            _ => explain_bad_address(end),
+        }
+    } else if let Some((address, prefix)) = trimmed.split_once('/') {
+        match parse_ip(address) {
+            Err(_) => explain_bad_address(address.trim()),
+            Ok(_) if prefix.contains('/') => "too many slashes",
+            Ok(_) if prefix.trim().parse::<u8>().is_err() => "prefix length not numeric",
+            Ok(_) => "prefix length out of range",
+        }
+    } else {
+        explain_bad_address(trimmed)
+    };
+    Ok(reason.into())
+}
+
+/// CIDR 的首尾地址，即网络地址和广播地址（IPv6 为块内最后一个地址）
+#[tauri::command]
+pub fn cidr_to_range(cidr: String) -> Result<(String, String), String> {
+    let (addr, prefix, bits) = parse_cidr(&cidr)?;
+    let (first, last) = cidr_bounds(addr, prefix, bits);
+    Ok((format_addr(first, bits), format_addr(last, bits)))
+}
+
+/// a 的二进制位串，在第一个与 b 不同的位前插入 "|" 标记，并返回相同前缀长度；两者完全相同时标记在末尾
+#[tauri::command]
+pub fn prefix_match_binary(a: String, b: String) -> Result<(String, u8), String> {
+    let (a_num, a_bits) = parse_ip(&a)?;
+    let (b_num, b_bits) = parse_ip(&b)?;
+    if a_bits != b_bits {
+        return Err(format!("'{}' 与 '{}' 的地址族不一致", a.trim(), b.trim()));
+    }
+    let matched = common_prefix_len(a_num, b_num, a_bits);
+    let mut binary = format!("{:0width$b}", a_num, width = a_bits as usize);
+    binary.insert(matched as usize, '|');
+    Ok((binary, matched))
+}
+
+/// 把 CIDR 等分为 parts 个子网（parts 必须是 2 的幂，不超过主机位所能容纳的数量和 MAX_SUBNET_PAGE），按地址顺序返回
+#[tauri::command]
+pub fn split_subnet(cidr: String, parts: usize) -> Result<Vec<String>, String> {
+    let (_, prefix, bits) = parse_cidr(&cidr)?;
+    if !parts.is_power_of_two() {
+        return Err(format!("拆分数量 {} 必须是 2 的幂", parts));
+    }
+    if parts as u64 > MAX_SUBNET_PAGE {
+        return Err(format!("拆分数量 {} 超出上限 {}", parts, MAX_SUBNET_PAGE));
+    }
+    let extra_bits = parts.trailing_zeros();
+    if prefix as u32 + extra_bits > bits as u32 {
+        return Err(format!(
+            "CIDR '{}' 只有 {} 位主机位，无法拆分为 {} 个子网",
+            cidr.trim(),
+            bits - prefix,
+            parts
+        ));
+    }
+    enumerate_subnets_page(cidr, prefix + extra_bits as u8, 0, parts as u64)
+}
+
+/// 返回落在 CIDR 内的地址（保持输入顺序）；无法解析或地址族不同的条目直接跳过，不报错
+#[tauri::command]
+pub fn filter_in_cidr(ips: Vec<String>, cidr: String) -> Result<Vec<String>, String> {
+    let (addr, prefix, bits) = parse_cidr(&cidr)?;
+    let (network, broadcast) = cidr_bounds(addr, prefix, bits);
+    Ok(ips
+        .into_iter()
+        .filter(|ip| {
+            parse_ip(ip).is_ok_and(|(num, ip_bits)| ip_bits == bits && network <= num && num <= broadcast)
+        })
+        .collect())
+}
+
+/// 合并相邻或重叠的 CIDR，返回最少的覆盖集合；IPv4 与 IPv6 混用时报错
+#[tauri::command]
+pub fn aggregate_cidrs(cidrs: Vec<String>) -> Result<Vec<String>, String> {
+    aggregate_cidr_list(&cidrs)
+}
+
+/// IPv4 全空间中不属于输入 CIDR 并集的部分，返回最少的 CIDR 集合
+#[tauri::command]
+pub fn complement_ipv4(cidrs: Vec<String>) -> Result<Vec<String>, String> {
+    let (intervals, family) = parse_cidr_list(&cidrs)?;
+    if family.is_some_and(|bits| bits != 32) {
+        return Err("complement_ipv4 仅支持 IPv4 CIDR".into());
+    }
+    let remaining = subtract_intervals(&[(0, u32::MAX as u128)], &intervals);
+    Ok(intervals_to_cidrs(&remaining, 32))
+}
+
+/// 地址是否在 CIDR 内：ip & mask == network；地址族不一致时报错
+#[tauri::command]
+pub fn ip_in_cidr(ip: String, cidr: String) -> Result<bool, String> {
+    let ip_is_v4 = match IpTranslationType::from_str(&ip) {
+        IpTranslationType::Ipv4 => true,
+        IpTranslationType::Ipv6 => false,
+        _ => return Err(format!("'{}' 不是有效的 IP 地址", ip.trim())),
+    };
+    let cidr_is_v4 = match IpTranslationType::from_str(&cidr) {
+        IpTranslationType::Ipv4Mask => true,
+        IpTranslationType::Ipv6Mask => false,
+        _ => return Err(format!("'{}' 不是有效的 CIDR", cidr.trim())),
+    };
+    if ip_is_v4 != cidr_is_v4 {
+        return Err(format!("地址 '{}' 与 CIDR '{}' 的地址族不一致", ip.trim(), cidr.trim()));
+    }
+
+    let (num, _) = parse_ip(split_zone(ip.trim()).0)?;
+    let (addr, prefix, bits) = parse_cidr(&cidr)?;
+    let mask = prefix_mask(prefix, bits);
+    Ok(num & mask == addr & mask)
+}
+
+/// 按 /24 统计地址占用率：块内去重后的地址数 / 256，按网段顺序返回
+#[tauri::command]
+pub fn density_by_slash24(ips: Vec<String>) -> Result<Vec<(String, f64)>, String> {
+    let mut nums = Vec::with_capacity(ips.len());
+    for ip in &ips {
+        let ipv4 = Ipv4Addr::from_str(ip.trim()).map_err(|e| format!("无效的 IPv4 地址 '{}': {}", ip, e))?;
+        nums.push(ipv4_to_u32(&ipv4));
+    }
+    nums.sort_unstable();
+    nums.dedup();
+
+    let mut density: Vec<(u32, usize)> = Vec::new();
+    for num in nums {
+        match density.last_mut() {
+            Some((block, count)) if *block == num >> 8 => *count += 1,
+            _ => density.push((num >> 8, 1)),
+        }
+    }
+    Ok(density
+        .into_iter()
+        .map(|(block, count)| (format!("{}/24", u32_to_ipv4(block << 8)), count as f64 / 256.0))
+        .collect())
+}
+
+/// 两个 CIDR 是否有公共地址；地址族不一致时报错
+#[tauri::command]
+pub fn cidrs_overlap(a: String, b: String) -> Result<bool, String> {
+    let (a_addr, a_prefix, a_bits) = parse_cidr(&a)?;
+    let (b_addr, b_prefix, b_bits) = parse_cidr(&b)?;
+    if a_bits != b_bits {
+        return Err(format!("CIDR '{}' 与 '{}' 的地址族不一致", a.trim(), b.trim()));
+    }
+    Ok(intervals_overlap(
+        cidr_bounds(a_addr, a_prefix, a_bits),
+        cidr_bounds(b_addr, b_prefix, b_bits),
+    ))
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassifiedLists {
+    pub ipv4: Vec<String>, // 含整数形式
+    pub ipv6: Vec<String>,
+    pub cidrs: Vec<String>,
+    pub ranges: Vec<String>, // 含降序范围，由 translate_ip 给出具体错误
+    pub unknown: Vec<String>,
+}
+
+/// 按识别类型把混合列表分桶，保持各桶内的输入顺序；空白条目忽略
+#[tauri::command]
+pub fn classify_list(inputs: Vec<String>) -> Result<ClassifiedLists, String> {
+    let mut lists = ClassifiedLists {
+        ipv4: Vec::new(),
+        ipv6: Vec::new(),
+        cidrs: Vec::new(),
+        ranges: Vec::new(),
+        unknown: Vec::new(),
+    };
+    for input in inputs {
+        let trimmed = input.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        let bucket = match IpTranslationType::from_str(trimmed) {
+            IpTranslationType::Ipv4 | IpTranslationType::Ipv4Num => &mut lists.ipv4,
+            IpTranslationType::Ipv6 | IpTranslationType::Ipv6Num => &mut lists.ipv6,
+            IpTranslationType::Ipv4Mask | IpTranslationType::Ipv6Mask => &mut lists.cidrs,
+            IpTranslationType::Ipv4Range
+            | IpTranslationType::Ipv6Range
+            | IpTranslationType::DescendingRange
+            | IpTranslationType::StartPlusSize => &mut lists.ranges,
+            IpTranslationType::UnknownIp => &mut lists.unknown,
+        };
+        bucket.push(trimmed.to_string());
+    }
+    Ok(lists)
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchTranslation {
+    pub input: String,
+    pub result: Option<IpTranslationResult>,
+    pub error: Option<String>, // 单条失败只记录在这里，不影响其余条目
+}
+
+/// 一次 IPC 调用批量转换，按输入顺序返回，每条单独给出结果或错误
+#[tauri::command]
+pub fn translate_ip_batch(ips: Vec<String>) -> Vec<BatchTranslation> {
+    ips.into_iter()
+        .map(|ip| {
+            let (result, error) = match translate_ip(ip.clone()) {
+                Ok(result) => (Some(result), None),
+                Err(error) => (None, Some(error)),
+            };
+            BatchTranslation { input: ip, result, error }
+        })
+        .collect()
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NormalizedCidr {
+    pub input: String,
+    pub canonical: String,
+    pub host_bits_set: bool,
+}
+
+/// 同时返回原样输入和清零主机位后的规范 CIDR
+#[tauri::command]
+pub fn normalize_cidr(cidr: String) -> Result<NormalizedCidr, String> {
+    let (addr, prefix, bits) = parse_cidr(&cidr)?;
+    let (network, _) = cidr_bounds(addr, prefix, bits);
+    Ok(NormalizedCidr {
+        input: cidr.trim().to_string(),
+        canonical: format!("{}/{}", format_addr(network, bits), prefix),
+        host_bits_set: network != addr,
+    })
+}
+
+/// split_u128_to_i64 的逆运算：由有符号高、低 64 位还原地址；IPv4 要求高位为 0 且低位在 32 位范围内
+#[tauri::command]
+pub fn join_i64_pair(high: i64, low: i64, family: String) -> Result<String, String> {
+    let bits = parse_family(&family)?;
+    let num = ((high as u64 as u128) << 64) | low as u64 as u128;
+    if bits == 32 && num > u32::MAX as u128 {
+        return Err(format!("({}, {}) 超出 IPv4 范围，高 64 位须为 0 且低 64 位在 0 ~ 2^32-1 之间", high, low));
+    }
+    Ok(format_addr(num, bits))
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AllocCounts {
+    pub slash48s: String, // 输入前缀比目标更长时留空
+    pub slash56s: String,
+    pub slash64s: String,
+}
+
+/// IPv6 前缀可划分出的 /48、/56、/64 数量（2^(目标 - 前缀)），供运营商分配参考
+#[tauri::command]
+pub fn ipv6_allocation_counts(cidr: String) -> Result<AllocCounts, String> {
+    let (_, prefix, bits) = parse_cidr(&cidr)?;
+    if bits != 128 {
+        return Err(format!("'{}' 不是 IPv6 CIDR", cidr.trim()));
+    }
+    let count = |target: u8| {
+        if prefix > target {
+            String::new()
+        } else {
+            block_size_string(target - prefix)
+        }
+    };
+    Ok(AllocCounts {
+        slash48s: count(48),
+        slash56s: count(56),
+        slash64s: count(64),
+    })
+}
+
+/// 按 inet_aton 规则展开点分简写：a.b -> a 占 8 位、b 占低 24 位；a.b.c -> a、b 各占 8 位、c 占低 16 位。
+/// 各段只按十进制读取，inet_aton 会按八进制解析的前导零段直接拒绝
+fn expand_ipv4_shorthand(input: &str) -> Result<Ipv4Addr, String> {
+    let rules = "简写规则：a.b 中 b 占低 24 位（0~16777215），a.b.c 中 c 占低 16 位（0~65535），其余各段 0~255";
+    let parts: Vec<&str> = input.trim().split('.').collect();
+    if !(2..=3).contains(&parts.len()) {
+        return Err(format!("'{}' 不是点分简写 IPv4，{}", input.trim(), rules));
+    }
+    if parts.iter().any(|p| has_leading_zero(p)) {
+        return Err(format!(
+            "'{}' 含前导零的段：inet_aton 会按八进制解析（如 010 为 8），存在歧义，已拒绝",
+            input.trim()
+        ));
+    }
+    let values = parts
+        .iter()
+        .map(|p| p.parse::<u32>().ok().filter(|_| p.chars().all(|c| c.is_ascii_digit())))
+        .collect::<Option<Vec<u32>>>()
+        .ok_or_else(|| format!("'{}' 含非数字的段，{}", input.trim(), rules))?;
+
+    let (head, last) = values.split_at(values.len() - 1);
+    let last_bits = 32 - 8 * head.len() as u32;
+    if head.iter().any(|&v| v > 255) || last[0] >= 1u32 << last_bits {
+        return Err(format!("'{}' 有段超出范围，{}", input.trim(), rules));
+    }
+    let num = (head.iter().fold(0u32, |acc, &v| (acc << 8) | v) << last_bits) | last[0];
+    Ok(u32_to_ipv4(num))
+}
+
+/// 与 translate_ip 相同，但额外接受 10.1、192.168.1 这类点分简写（展开为 10.0.0.1、192.168.0.1）。
+/// 简写与普通输入有歧义，因此默认的 translate_ip 不做展开
+#[tauri::command]
+pub fn translate_ip_shorthand(ip: String) -> Result<IpTranslationResult, String> {
+    let is_shorthand = matches!(IpTranslationType::from_str(&ip), IpTranslationType::UnknownIp)
+        && (1..=2).contains(&ip.trim().matches('.').count());
+    if !is_shorthand {
+        return translate_ip(ip);
+    }
+    let ipv4 = expand_ipv4_shorthand(&ip)?;
+    let mut result = translate_ip(ipv4.to_string())?;
+    result.field_type = "IPv4（简写展开）".into();
+    result.ex_address = ip.trim().into(); // 保留原始简写
+    Ok(result)
+}
+
+/// 适合粘贴到聊天里的单行子网摘要，如 "10.0.0.0/24 | mask 255.255.255.0 | 254 hosts | .1-.254 | bc 10.0.0.255"。
+/// 可用主机段省略首尾相同的前导八位组；/31、/32 没有广播地址，不输出 bc 部分
+#[tauri::command]
+pub fn subnet_one_liner(cidr: String) -> Result<String, String> {
+    let (addr, prefix, bits) = parse_cidr(&cidr)?;
+    if bits != 32 {
+        return Err(format!("'{}' 不是 IPv4 CIDR", cidr.trim()));
+    }
+    let (network, broadcast) = cidr_bounds(addr, prefix, bits);
+    let edge = if prefix < 31 { 1 } else { 0 };
+    let first = u32_to_ipv4((network + edge) as u32).octets();
+    let last = u32_to_ipv4((broadcast - edge) as u32).octets();
+    let common = first.iter().zip(&last).take(3).take_while(|(a, b)| a == b).count();
+    let suffix = |octets: &[u8; 4]| {
+        octets[common..]
+            .iter()
+            .map(|o| format!(".{}", o))
+            .collect::<String>()
+    };
+
+    let mut parts = vec![
+        format!("{}/{}", format_addr(network, bits), prefix),
+        format!("mask {}", format_addr(prefix_mask(prefix, bits), bits)),
+        match ipv4_usable_hosts(prefix) {
+            1 => "1 host".to_string(),
+            hosts => format!("{} hosts", hosts),
+        },
+        format!("{}-{}", suffix(&first), suffix(&last)),
+    ];
+    if prefix < 31 {
+        parts.push(format!("bc {}", format_addr(broadcast, bits)));
+    }
+    Ok(parts.join(" | "))
+}
+
+/// 把 "起始地址+数量" 写法（如 10.0.0.0+256）转换为等价的 "start-end" 范围，超出地址空间时报错
+#[tauri::command]
+pub fn parse_start_plus_size(input: String) -> Result<String, String> {
+    let (start, size) = input
+        .trim()
+        .split_once('+')
+        .ok_or_else(|| format!("无效的 '起始地址+数量' 格式 '{}'", input.trim()))?;
+    let (num, bits) = parse_ip(start)?;
+    if !is_decimal_size(size.trim()) {
+        return Err(format!("无效的地址数量 '{}'，应为十进制正整数", size.trim()));
+    }
+    let size: u128 = size
+        .trim()
+        .parse()
+        .map_err(|e| format!("无效的地址数量 '{}': {}", size.trim(), e))?;
+    if size == 0 {
+        return Err("地址数量必须大于 0".into());
+    }
+    if size - 1 > host_span(bits) - num {
+        return Err(format!("从 '{}' 起 {} 个地址会超出地址空间", start.trim(), size));
+    }
+    Ok(format!("{}-{}", format_addr(num, bits), format_addr(num + (size - 1), bits)))
+}
+
+/// 覆盖所有输入 CIDR 的最小范围 "最小网络地址 - 最大广播地址"，要求同一地址族
+#[tauri::command]
+pub fn cidrs_bounding_range(cidrs: Vec<String>) -> Result<String, String> {
+    let (intervals, family) = parse_cidr_list(&cidrs)?;
+    let (Some(bits), Some(first), Some(last)) = (family, intervals.first(), intervals.last()) else {
+        return Err("CIDR 列表不能为空".into());
+    };
+    Ok(format!("{} - {}", format_addr(first.0, bits), format_addr(last.1, bits)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_hex_octets_requires_prefixed_hex() {
+        assert_eq!(
+            parse_hex_octets("0xC0.0xA8.0x01.0x01".into()).unwrap(),
+            "192.168.1.1"
+        );
+        assert_eq!(
+            parse_hex_octets("0Xff.0x0.0x0.0x1".into()).unwrap(),
+            "255.0.0.1"
+        );
+        assert!(parse_hex_octets("10.0.0.1".into()).is_err());
+        assert!(parse_hex_octets("0x+1.0x0.0x0.0x1".into()).is_err());
+        assert!(parse_hex_octets("0x.0x0.0x0.0x1".into()).is_err());
+        assert!(parse_hex_octets("0x1.0x2".into()).is_err());
+    }
+
+    #[test]
+    fn cidr_symmetric_difference_keeps_non_shared_part() {
+        assert_eq!(
+            cidr_symmetric_difference(strings(&["10.0.0.0/24"]), strings(&["10.0.0.0/25"]))
+                .unwrap(),
+            strings(&["10.0.0.128/25"])
+        );
+        assert_eq!(
+            cidr_symmetric_difference(strings(&["::/0"]), strings(&["::/1"])).unwrap(),
+            strings(&["8000::/1"])
+        );
+        assert!(cidr_symmetric_difference(strings(&["10.0.0.0/24"]), strings(&["::/1"])).is_err());
+    }
+
+    #[test]
+    fn hilbert_xy_follows_curve_order() {
+        assert_eq!(hilbert_xy("0.0.0.0".into(), 1).unwrap(), (0, 0));
+        assert_eq!(hilbert_xy("64.0.0.0".into(), 1).unwrap(), (0, 1));
+        assert_eq!(hilbert_xy("128.0.0.0".into(), 1).unwrap(), (1, 1));
+        assert_eq!(hilbert_xy("192.0.0.0".into(), 1).unwrap(), (1, 0));
+
+        // 2 阶曲线 d = 0..15 的标准坐标
+        let expected = [
+            (0, 0),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (1, 3),
+            (1, 2),
+            (2, 2),
+            (2, 3),
+            (3, 3),
+            (3, 2),
+            (3, 1),
+            (2, 1),
+            (2, 0),
+            (3, 0),
+        ];
+        for (d, xy) in expected.iter().enumerate() {
+            let ip = u32_to_ipv4((d as u32) << 28).to_string();
+            assert_eq!(hilbert_xy(ip, 2).unwrap(), *xy, "d = {}", d);
+        }
+        assert!(hilbert_xy("1.2.3.4".into(), 17).is_err());
+    }
+
+    #[test]
+    fn well_known_resolves_names() {
+        assert_eq!(well_known("ipv4-any".into()).unwrap(), "0.0.0.0");
+        assert_eq!(
+            well_known("ipv4-broadcast".into()).unwrap(),
+            "255.255.255.255"
+        );
+        assert_eq!(well_known("ipv6-any".into()).unwrap(), "::");
+        assert_eq!(well_known("ipv6-all-nodes".into()).unwrap(), "ff02::1");
+        assert_eq!(well_known("ipv6-all-routers".into()).unwrap(), "ff02::2");
+        assert!(well_known("nope".into()).is_err());
+    }
+
+    /// 返回块列表覆盖 [start, end] 时多覆盖的地址数，块必须连续覆盖整个范围
+    fn cover_waste(cidrs: &[String], start: u128, end: u128) -> u128 {
+        let mut bounds: Vec<Interval> = cidrs
+            .iter()
+            .map(|cidr| {
+                let (addr, prefix, bits) = parse_cidr(cidr).unwrap();
+                cidr_bounds(addr, prefix, bits)
+            })
+            .collect();
+        bounds.sort_unstable();
+        assert!(
+            bounds[0].0 <= start && bounds[bounds.len() - 1].1 >= end,
+            "{:?}",
+            cidrs
+        );
+        assert!(
+            bounds.windows(2).all(|w| w[0].1 + 1 == w[1].0),
+            "{:?}",
+            cidrs
+        );
+        bounds.iter().map(|(lo, hi)| hi - lo + 1).sum::<u128>() - (end - start + 1)
+    }
+
+    /// 暴力求解：在 /27 内用不超过 k 个对齐块覆盖 [start, end] 的最少多覆盖数
+    fn brute_force_waste(base: u128, start: u128, end: u128, k: usize) -> u128 {
+        fn rest(base: u128, covered_to: u128, end: u128, k: usize) -> Option<u128> {
+            if covered_to >= end {
+                return Some(covered_to - end);
+            }
+            if k == 0 {
+                return None;
+            }
+            let pos = covered_to + 1;
+            (0..=5u8)
+                .filter(|&hb| pos & host_span(hb) == 0 && (pos | host_span(hb)) <= base + 31)
+                .filter_map(|hb| rest(base, pos | host_span(hb), end, k - 1))
+                .min()
+        }
+        (0..=5u8)
+            .filter_map(|hb| {
+                let first = start & !host_span(hb);
+                rest(base, first | host_span(hb), end, k - 1).map(|waste| waste + (start - first))
+            })
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    fn range_to_cidrs_limited_respects_budget() {
+        // 精确拆分为 .1/32 .2/31 .4/30 .8/31 .10/32 共 5 块
+        assert_eq!(
+            range_to_cidrs_limited("10.0.0.1-10.0.0.10".into(), 5)
+                .unwrap()
+                .len(),
+            5
+        );
+        let limited = range_to_cidrs_limited("10.0.0.1-10.0.0.10".into(), 3).unwrap();
+        assert!(limited.len() <= 3, "{:?}", limited);
+        cover_waste(&limited, 0x0A00_0001, 0x0A00_000A);
+
+        assert_eq!(
+            range_to_cidrs_limited("10.0.0.1-10.0.0.4".into(), 2).unwrap(),
+            strings(&["10.0.0.0/30", "10.0.0.4/32"])
+        );
+        assert_eq!(
+            range_to_cidrs_limited("10.0.0.0-10.0.0.10".into(), 2).unwrap(),
+            strings(&["10.0.0.0/29", "10.0.0.8/30"])
+        );
+    }
+
+    #[test]
+    fn range_to_cidrs_limited_is_minimal_within_slash27() {
+        let base: u128 = 0x0A00_0000;
+        for start in base..base + 32 {
+            for end in start..base + 32 {
+                for k in 1..=3 {
+                    let range = format!("{}-{}", format_addr(start, 32), format_addr(end, 32));
+                    let cidrs = range_to_cidrs_limited(range.clone(), k).unwrap();
+                    assert!(cidrs.len() <= k, "{} k={} -> {:?}", range, k, cidrs);
+                    assert_eq!(
+                        cover_waste(&cidrs, start, end),
+                        brute_force_waste(base, start, end, k),
+                        "{} k={} -> {:?}",
+                        range,
+                        k,
+                        cidrs
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn octets_splits_both_families() {
+        assert_eq!(octets("192.168.1.1".into()).unwrap(), vec![192, 168, 1, 1]);
+        assert_eq!(
+            octets("2001:db8::1".into()).unwrap(),
+            vec![0x2001, 0xdb8, 0, 0, 0, 0, 0, 1]
+        );
+        assert!(octets("x".into()).is_err());
+    }
+
+    #[test]
+    fn range_as_single_cidr_requires_exact_block() {
+        assert_eq!(
+            range_as_single_cidr("10.0.0.0-10.0.0.255".into()).unwrap(),
+            "10.0.0.0/24"
+        );
+        assert!(range_as_single_cidr("10.0.0.0-10.0.0.100".into()).is_err());
+    }
+
+    #[test]
+    fn host_position_fraction_of_midpoint() {
+        let fraction = host_position_fraction("10.0.0.128/24".into()).unwrap();
+        assert!((fraction - 0.502).abs() < 0.001, "{}", fraction);
+        assert_eq!(host_position_fraction("10.0.0.1/32".into()).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn address_before_block_steps_back_one() {
+        assert_eq!(
+            address_before_block("10.0.1.0/24".into()).unwrap(),
+            "10.0.0.255"
+        );
+        assert!(address_before_block("0.0.0.0/24".into()).is_err());
+    }
+
+    #[test]
+    fn range_cidr_sizes_lists_prefixes_in_order() {
+        assert_eq!(
+            range_cidr_sizes("10.0.0.0-10.0.0.130".into()).unwrap(),
+            vec![25, 31, 32]
+        );
+    }
+
+    #[test]
+    fn translate_ip_reports_ipv6_group_overflow() {
+        let err = translate_ip("1:2:3:4:5:6:7:1.2.3.4".into()).unwrap_err();
+        assert!(err.contains("IPv6 段数超出"), "{}", err);
+        assert!(translate_ip("1:2:3:4:5:6:1.2.3.4".into()).is_ok());
+    }
+
+    #[test]
+    fn network_of_clears_host_bits() {
+        assert_eq!(network_of("10.0.0.200".into(), 24).unwrap(), "10.0.0.0");
+        assert_eq!(
+            network_of("2001:db8::abcd".into(), 64).unwrap(),
+            "2001:db8::"
+        );
+        assert!(network_of("10.0.0.1".into(), 33).is_err());
+    }
+
+    #[test]
+    fn prefix_table_ipv4_rows() {
+        let table = prefix_table("ipv4".into()).unwrap();
+        assert_eq!(table.len(), 33);
+        assert_eq!(table[24].prefix, 24);
+        assert_eq!(table[24].addresses, "256");
+        assert_eq!(table[24].usable, "254");
+        assert_eq!(prefix_table("ipv6".into()).unwrap().len(), 129);
+    }
+
+    #[test]
+    fn suggest_fix_names_out_of_range_octet() {
+        let hint = suggest_fix("192.168.1.256".into()).unwrap();
+        assert!(hint.contains("第 4 段"), "{}", hint);
+        assert!(hint.contains("192.168.1.255"), "{}", hint);
+        assert!(suggest_fix("192.168.1.1".into()).is_none());
+    }
+
+    #[test]
+    fn touched_slash24s_lists_every_block() {
+        assert_eq!(
+            touched_slash24s("10.0.0.200-10.0.1.50".into()).unwrap(),
+            strings(&["10.0.0.0/24", "10.0.1.0/24"])
+        );
+        assert!(touched_slash24s("::1-::2".into()).is_err());
+    }
+
+    #[test]
+    fn boundary_roles_of_broadcast_address() {
+        let roles = boundary_roles("10.0.0.255".into()).unwrap();
+        assert!(
+            roles.contains(&(24, "broadcast".to_string())),
+            "{:?}",
+            roles
+        );
+        assert!(
+            roles.contains(&(31, "point-to-point".to_string())),
+            "{:?}",
+            roles
+        );
+        assert!(!roles.iter().any(|(prefix, _)| *prefix == 23));
+    }
+
+    #[test]
+    fn verify_roundtrip_holds_for_addresses() {
+        assert!(verify_roundtrip("1.2.3.4".into()).unwrap());
+        assert!(verify_roundtrip("2001:db8::1".into()).unwrap());
+        assert!(verify_roundtrip("1.1.1.1-1.1.1.2".into()).is_err());
+    }
+
+    #[test]
+    fn ipv6_nibbles_returns_32_nibbles() {
+        let nibbles = ipv6_nibbles("2001:db8::1".into()).unwrap();
+        assert_eq!(nibbles.len(), 32);
+        assert_eq!(&nibbles[..8], &[2, 0, 0, 1, 0, 0xd, 0xb, 8]);
+        assert_eq!(nibbles[31], 1);
+        assert!(ipv6_nibbles("1.2.3.4".into()).is_err());
+    }
+
+    #[test]
+    fn is_cgnat_matches_shared_space() {
+        assert!(is_cgnat("100.64.0.1".into()).unwrap());
+        assert!(!is_cgnat("100.128.0.1".into()).unwrap());
+        assert!(is_cgnat("::1".into()).is_err());
+    }
+
+    #[test]
+    fn ula_breakdown_decodes_fd_prefix() {
+        let ula = ula_breakdown("fd12:3456:789a:1::1".into()).unwrap();
+        assert_eq!(ula.global_id, "123456789a");
+        assert_eq!(ula.subnet_id, "0001");
+        assert!(ula.is_locally_assigned);
+        assert!(ula_breakdown("2001:db8::1".into()).is_err());
+    }
+
+    #[test]
+    fn separating_prefix_of_adjacent_halves() {
+        assert_eq!(
+            separating_prefix("10.0.0.0".into(), "10.0.0.128".into()).unwrap(),
+            25
+        );
+        assert!(separating_prefix("1.1.1.1".into(), "1.1.1.1".into()).is_err());
+    }
+
+    #[test]
+    fn halve_splits_block_in_two() {
+        assert_eq!(
+            halve("10.0.0.0/24".into()).unwrap(),
+            ("10.0.0.0/25".to_string(), "10.0.0.128/25".to_string())
+        );
+        assert!(halve("10.0.0.0/32".into()).is_err());
+    }
+
+    #[test]
+    fn is_cidr_only_accepts_prefix_notation() {
+        assert!(is_cidr("10.0.0.0/24".into()));
+        assert!(!is_cidr("10.0.0.0".into()));
+        assert!(!is_cidr("10.0.0.0-10.0.0.5".into()));
+    }
+
+    #[test]
+    fn union_size_counts_overlap_once() {
+        assert_eq!(
+            union_size("10.0.0.0-10.0.0.9".into(), "10.0.0.5-10.0.0.14".into()).unwrap(),
+            "15"
+        );
+        assert!(union_size("1.1.1.1-1.1.1.2".into(), "::1-::2".into()).is_err());
+    }
+
+    #[test]
+    fn ipv6_host_fraction_of_midpoint() {
+        let fraction = ipv6_host_fraction("2001:db8::8000:0:0:0/64".into()).unwrap();
+        assert!((fraction - 0.5).abs() < 1e-9, "{}", fraction);
+        assert!(ipv6_host_fraction("1.1.1.1/24".into()).is_err());
+    }
+
+    #[test]
+    fn wildcard_matches_respects_dont_care_bits() {
+        assert!(wildcard_matches("10.0.0.0 0.0.255.0".into(), "10.0.5.0".into()).unwrap());
+        assert!(!wildcard_matches("10.0.0.0 0.0.255.0".into(), "10.1.0.0".into()).unwrap());
+    }
+
+    #[test]
+    fn prefix_list_numbers_permit_lines() {
+        let config =
+            prefix_list(strings(&["10.0.0.0/24", "2001:db8::1/32"]), "CUST".into()).unwrap();
+        assert_eq!(
+            config,
+            "ip prefix-list CUST seq 5 permit 10.0.0.0/24\nipv6 prefix-list CUST seq 10 permit 2001:db8::/32"
+        );
+        assert!(prefix_list(Vec::new(), "a b".into()).is_err());
+    }
+
+    #[test]
+    fn multicast_scope_reads_scope_nibble() {
+        assert_eq!(multicast_scope("ff02::1".into()).unwrap(), "link-local");
+        assert_eq!(multicast_scope("ff0e::1".into()).unwrap(), "global");
+        assert!(multicast_scope("fe80::1".into()).is_err());
+    }
+
+    #[test]
+    fn multicast_lookup_finds_all_nodes() {
+        let found = multicast_lookup("all-nodes".into()).unwrap();
+        assert!(
+            found.contains(&("ff02::1".to_string(), "all-nodes".to_string())),
+            "{:?}",
+            found
+        );
+        assert!(multicast_lookup("zzz".into()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn aggregation_savings_of_four_quarters() {
+        let savings = aggregation_savings(strings(&[
+            "10.0.0.0/26",
+            "10.0.0.64/26",
+            "10.0.0.128/26",
+            "10.0.0.192/26",
+        ]))
+        .unwrap();
+        assert_eq!(
+            (savings.before, savings.after, savings.reduced_by),
+            (4, 1, 3)
+        );
+    }
+
+    #[test]
+    fn canonicalize_blob_rewrites_addresses_in_place() {
+        assert_eq!(
+            canonicalize_blob("route 2001:DB8:0:0::/64 next-hop fe80::1".into()).unwrap(),
+            "route 2001:db8::/64 next-hop fe80::1"
+        );
+        assert_eq!(canonicalize_blob("add bead".into()).unwrap(), "add bead");
+    }
+
+    #[test]
+    fn blackhole_slash24s_marks_full_blocks() {
+        assert_eq!(
+            blackhole_slash24s("10.0.0.0-10.0.1.100".into()).unwrap(),
+            vec![
+                ("10.0.0.0/24".to_string(), true),
+                ("10.0.1.0/24".to_string(), false)
+            ]
+        );
+    }
+
+    #[test]
+    fn u64_pair_round_trips() {
+        let (high, low) = to_u64_pair("2001:db8::1".into()).unwrap();
+        assert_eq!(low, "1");
+        assert_eq!(from_u64_pair(high, low).unwrap(), "2001:db8::1");
+        assert!(from_u64_pair("-1".into(), "0".into()).is_err());
+    }
+
+    #[test]
+    fn would_overlap_reports_first_conflict() {
+        assert_eq!(
+            would_overlap(strings(&["10.0.0.0/24"]), "10.0.0.0/25".into()).unwrap(),
+            Some("10.0.0.0/24".to_string())
+        );
+        assert_eq!(
+            would_overlap(strings(&["10.0.0.0/24", "::/0"]), "10.0.1.0/25".into()).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn address_at_percent_of_slash24() {
+        assert_eq!(
+            address_at_percent("10.0.0.0/24".into(), 50.0).unwrap(),
+            "10.0.0.128"
+        );
+        assert_eq!(
+            address_at_percent("10.0.0.0/24".into(), 0.0).unwrap(),
+            "10.0.0.0"
+        );
+        assert_eq!(
+            address_at_percent("10.0.0.0/24".into(), 150.0).unwrap(),
+            "10.0.0.255"
+        );
+    }
+
+    #[test]
+    fn from_octets_validates_each_octet() {
+        assert_eq!(from_octets(192, 168, 1, 1).unwrap(), "192.168.1.1");
+        let err = from_octets(192, 168, 1, 300).unwrap_err();
+        assert!(err.contains("第 4 段"), "{}", err);
+    }
+
+    #[test]
+    fn from_segments_rebuilds_address() {
+        assert_eq!(
+            from_segments(vec![0x2001, 0xdb8, 0, 0, 0, 0, 0, 1]).unwrap(),
+            "2001:db8::1"
+        );
+        assert!(from_segments(vec![1, 2]).is_err());
+    }
+
+    #[test]
+    fn nearest_prefix_label_fits_host_count() {
+        assert_eq!(nearest_prefix_label(200, "ipv4".into()).unwrap(), "/24");
+        assert_eq!(nearest_prefix_label(255, "ipv4".into()).unwrap(), "/23");
+        assert_eq!(nearest_prefix_label(256, "ipv6".into()).unwrap(), "/120");
+    }
+
+    #[test]
+    fn rfc2317_zone_for_slash26() {
+        assert_eq!(
+            rfc2317_zone("10.0.0.0/26".into()).unwrap(),
+            "0-63.0.0.10.in-addr.arpa"
+        );
+        assert!(rfc2317_zone("10.0.0.0/24".into()).is_err());
+    }
+
+    #[test]
+    fn ipv6_compression_map_marks_elided_groups() {
+        // 2001:db8::1 中下标 2~6 的段被 "::" 省略
+        assert_eq!(
+            ipv6_compression_map("2001:db8::1".into()).unwrap(),
+            vec![false, false, true, true, true, true, true, false]
+        );
+        assert_eq!(
+            ipv6_compression_map("1:2:3:4:5:6:7:8".into()).unwrap(),
+            vec![false; 8]
+        );
+    }
+
+    #[test]
+    fn enumerate_subnets_page_returns_requested_window() {
+        assert_eq!(
+            enumerate_subnets_page("10.0.0.0/16".into(), 24, 1, 2).unwrap(),
+            strings(&["10.0.1.0/24", "10.0.2.0/24"])
+        );
+        assert!(enumerate_subnets_page("10.0.0.0/16".into(), 24, 256, 1).is_err());
+    }
+
+    #[test]
+    fn enumerate_subnets_page_handles_full_ipv6_split() {
+        assert_eq!(
+            enumerate_subnets_page("::/0".into(), 128, 0, 2).unwrap(),
+            strings(&["::/128", "::1/128"])
+        );
+        assert_eq!(
+            enumerate_subnets_page("::/0".into(), 0, 0, 3).unwrap(),
+            strings(&["::/0"])
+        );
+    }
+
+    #[test]
+    fn reflect_in_cidr_mirrors_around_midpoint() {
+        assert_eq!(
+            reflect_in_cidr("10.0.0.1".into(), "10.0.0.0/24".into()).unwrap(),
+            "10.0.0.254"
+        );
+        assert!(reflect_in_cidr("10.0.1.1".into(), "10.0.0.0/24".into()).is_err());
+    }
+
+    #[test]
+    fn translate_ip_flags_mixed_notation() {
+        assert!(
+            translate_ip("::ffff:1.2.3.4".into())
+                .unwrap()
+                .input_used_mixed_notation
+        );
+        assert!(
+            !translate_ip("::ffff:102:304".into())
+                .unwrap()
+                .input_used_mixed_notation
+        );
+    }
+
+    #[test]
+    fn single_aggregate_of_two_halves_is_fully_efficient() {
+        let aggregate = single_aggregate(strings(&["10.0.0.0/25", "10.0.0.128/25"])).unwrap();
+        assert_eq!(aggregate.cidr, "10.0.0.0/24");
+        assert_eq!(aggregate.efficiency_percent, 100.0);
+        let aggregate = single_aggregate(strings(&["10.0.0.0/25", "10.0.1.0/25"])).unwrap();
+        assert_eq!(aggregate.cidr, "10.0.0.0/23");
+        assert_eq!(aggregate.efficiency_percent, 50.0);
+    }
+
+    #[test]
+    fn evaluate_policy_uses_first_matching_rule() {
+        let rules = vec![
+            ("deny".to_string(), "10.0.0.0/25".to_string()),
+            ("allow".to_string(), "10.0.0.0/24".to_string()),
+        ];
+        assert_eq!(
+            evaluate_policy("10.0.0.5".into(), rules.clone()).unwrap(),
+            "deny"
+        );
+        assert_eq!(
+            evaluate_policy("10.0.0.200".into(), rules.clone()).unwrap(),
+            "allow"
+        );
+        assert_eq!(
+            evaluate_policy("10.0.1.200".into(), rules).unwrap(),
+            "default-deny"
+        );
+    }
+
+    #[test]
+    fn ipcalc_json_reports_hosts_and_network() {
+        let json = ipcalc_json("192.168.1.0/24".into()).unwrap();
+        let field = |key: &str| json.get(key).and_then(|v| v.as_str()).map(str::to_string);
+        assert_eq!(field("HOSTS").as_deref(), Some("254"));
+        assert_eq!(field("NETWORK").as_deref(), Some("192.168.1.0"));
+        assert_eq!(field("BROADCAST").as_deref(), Some("192.168.1.255"));
+    }
+
+    #[test]
+    fn shard_bucket_is_stable_and_bounded() {
+        for ip in ["1.2.3.4", "2001:db8::1", "255.255.255.255"] {
+            let bucket = shard_bucket(ip.into(), 7).unwrap();
+            assert_eq!(bucket, shard_bucket(ip.into(), 7).unwrap());
+            assert!(bucket < 7);
+        }
+        // 固定一个值，防止折叠方式被无意改动
+        assert_eq!(shard_bucket("0.0.0.10".into(), 7).unwrap(), 3);
+        assert!(shard_bucket("1.1.1.1".into(), 0).is_err());
+    }
+
+    #[test]
+    fn translate_ip_fields_keeps_only_requested_keys() {
+        let fields = translate_ip_fields(
+            "10.0.0.1".into(),
+            strings(&["intValue", "binaryAddress", "noSuchField"]),
+        )
+        .unwrap();
+        let mut keys: Vec<&String> = fields.keys().collect();
+        keys.sort();
+        assert_eq!(keys, vec!["binaryAddress", "intValue"]);
+    }
+
+    #[test]
+    fn range_to_cidrs_sorted_puts_largest_block_first() {
+        assert_eq!(
+            range_to_cidrs_sorted("10.0.0.0-10.0.0.130".into()).unwrap(),
+            strings(&["10.0.0.0/25", "10.0.0.128/31", "10.0.0.130/32"])
+        );
+    }
+
+    #[test]
+    fn cidr_representation_waste_against_covering_block() {
+        // 10.0.0.10-10.0.0.20 共 11 个地址，最小覆盖块为 10.0.0.0/27
+        assert_eq!(
+            cidr_representation_waste("10.0.0.10-10.0.0.20".into()).unwrap(),
+            "21"
+        );
+        assert_eq!(
+            cidr_representation_waste("10.0.0.0-10.0.0.255".into()).unwrap(),
+            "0"
+        );
+    }
+
+    #[test]
+    fn plan_fingerprint_ignores_order_and_spelling() {
+        let a =
+            plan_fingerprint(strings(&["10.0.0.0/24", "2001:db8::/32", "10.1.0.0/16"])).unwrap();
+        let b =
+            plan_fingerprint(strings(&["10.1.0.5/16", " 2001:DB8::/32", "10.0.0.0/24"])).unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, plan_fingerprint(strings(&["10.0.0.0/24"])).unwrap());
+    }
+
+    #[test]
+    fn translate_ip_expands_shorthand_range() {
+        let result = translate_ip("10.0.0.1-5".into()).unwrap();
+        assert_eq!(result.net_work_address, "10.0.0.1");
+        assert_eq!(result.broadcast_address, "10.0.0.5");
+        let err = translate_ip("10.0.0.1-300".into()).unwrap_err();
+        assert!(err.contains("300"), "{}", err);
+    }
+
+    #[test]
+    fn prefix_around_host_picks_smallest_fitting_block() {
+        assert_eq!(
+            prefix_around_host("10.0.0.37".into(), 50).unwrap(),
+            "10.0.0.0/26"
+        );
+        assert!(prefix_around_host("1.1.1.1".into(), u64::MAX).is_err());
+    }
+
+    #[test]
+    fn isatap_extract_reads_embedded_ipv4() {
+        assert_eq!(
+            isatap_extract("fe80::5efe:192.0.2.1".into()).unwrap(),
+            "192.0.2.1"
+        );
+        assert!(isatap_extract("fe80::1".into()).is_err());
+    }
+
+    #[test]
+    fn isatap_build_appends_interface_id() {
+        let address = isatap_build("fe80::/64".into(), "192.0.2.1".into()).unwrap();
+        assert_eq!(address, "fe80::5efe:c000:201");
+        assert_eq!(isatap_extract(address).unwrap(), "192.0.2.1");
+        assert!(isatap_build("fe80::/48".into(), "192.0.2.1".into()).is_err());
+    }
+
+    #[test]
+    fn ipv6_scope_category_boundaries() {
+        let cases = [
+            ("::", "unspecified"),
+            ("::1", "loopback"),
+            ("::2", "global-unicast"),
+            ("::fffe:ffff:ffff", "global-unicast"),
+            ("::ffff:0:0", "ipv4-mapped"),
+            ("::ffff:255.255.255.255", "ipv4-mapped"),
+            ("::1:0:0:0", "global-unicast"),
+            ("fe7f:ffff:ffff:ffff:ffff:ffff:ffff:ffff", "global-unicast"),
+            ("fe80::", "link-local"),
+            ("febf:ffff:ffff:ffff:ffff:ffff:ffff:ffff", "link-local"),
+            ("fec0::", "global-unicast"),
+            ("fbff:ffff:ffff:ffff:ffff:ffff:ffff:ffff", "global-unicast"),
+            ("fc00::", "unique-local"),
+            ("fdff:ffff:ffff:ffff:ffff:ffff:ffff:ffff", "unique-local"),
+            ("fe00::", "global-unicast"),
+            ("feff:ffff:ffff:ffff:ffff:ffff:ffff:ffff", "global-unicast"),
+            ("ff00::", "multicast"),
+            ("ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff", "multicast"),
+        ];
+        for (ip, scope) in cases {
+            assert_eq!(
+                ipv6_scope(&Ipv6Addr::from_str(ip).unwrap()),
+                scope,
+                "{}",
+                ip
+            );
+        }
+    }
+
+    #[test]
+    fn aggregate_with_report_contains_adjacent_halves() {
+        let report = aggregate_with_report(strings(&["10.0.0.0/25", "10.0.0.128/25"])).unwrap();
+        assert_eq!(report.aggregate, "10.0.0.0/24");
+        assert_eq!(report.contained, strings(&["10.0.0.0/25", "10.0.0.128/25"]));
+        assert!(report.outside.is_empty());
+    }
+
+    #[test]
+    fn parity_of_lowest_bit() {
+        assert_eq!(parity("10.0.0.2".into()).unwrap(), "even");
+        assert_eq!(parity("10.0.0.1".into()).unwrap(), "odd");
+        assert_eq!(parity("::1".into()).unwrap(), "odd");
+    }
+
+    #[test]
+    fn translate_ip_formats_ipv6_subnet_mask() {
+        assert_eq!(
+            translate_ip("2001:db8::/64".into()).unwrap().subnet_mask,
+            "ffff:ffff:ffff:ffff::"
+        );
+        assert_eq!(
+            translate_ip("2001:db8::/48".into()).unwrap().subnet_mask,
+            "ffff:ffff:ffff::"
+        );
+    }
+
+    #[test]
+    fn summarize_object_group_collapses_consecutive_ips() {
+        assert_eq!(
+            summarize_object_group(
+                strings(&["10.0.0.0", "10.0.0.1", "10.0.0.2", "10.0.0.3"]),
+                "SCAN".into()
+            )
+            .unwrap(),
+            "object-group network SCAN\n network-object 10.0.0.0/30"
+        );
+        assert!(summarize_object_group(strings(&["10.0.0.1"]), "a b".into()).is_err());
+    }
+
+    #[test]
+    fn usable_role_slash24_roles() {
+        assert_eq!(
+            usable_role_slash24("192.168.1.1".into()).unwrap(),
+            "first-usable"
+        );
+        assert_eq!(
+            usable_role_slash24("192.168.1.254".into()).unwrap(),
+            "last-usable"
+        );
+        assert_eq!(
+            usable_role_slash24("192.168.1.0".into()).unwrap(),
+            "network"
+        );
+        assert_eq!(
+            usable_role_slash24("192.168.1.255".into()).unwrap(),
+            "broadcast"
+        );
+        assert_eq!(usable_role_slash24("192.168.1.7".into()).unwrap(), "host");
+    }
+
+    #[test]
+    fn descending_ranges_report_same_error_for_both_spellings() {
+        assert!(matches!(
+            IpTranslationType::from_str("10.0.0.9-10.0.0.1"),
+            IpTranslationType::DescendingRange
+        ));
+        assert!(matches!(
+            IpTranslationType::from_str("10.0.0.5-1"),
+            IpTranslationType::DescendingRange
+        ));
+        assert!(matches!(
+            IpTranslationType::from_str("10.0.0.1-5"),
+            IpTranslationType::Ipv4Range
+        ));
+
+        let err = translate_ip("10.0.0.9-10.0.0.1".into()).unwrap_err();
+        assert!(
+            err.contains("范围是降序的") && err.contains("'10.0.0.1-10.0.0.9'"),
+            "{}",
+            err
+        );
+        let err = translate_ip("10.0.0.5-1".into()).unwrap_err();
+        assert!(
+            err.contains("范围是降序的") && err.contains("'10.0.0.1-10.0.0.5'"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn cidr_exclude_many_removes_all_holes() {
+        assert_eq!(
+            cidr_exclude_many(
+                "10.0.0.0/24".into(),
+                strings(&["10.0.0.0/26", "10.0.0.192/26"])
+            )
+            .unwrap(),
+            strings(&["10.0.0.64/26", "10.0.0.128/26"])
+        );
+        assert!(cidr_exclude_many("10.0.0.0/24".into(), strings(&["10.0.1.0/26"])).is_err());
+    }
+
+    #[test]
+    fn next_n_addresses_crosses_octet_boundary() {
+        assert_eq!(
+            next_n_addresses("10.0.0.254".into(), 3).unwrap(),
+            strings(&["10.0.0.254", "10.0.0.255", "10.0.1.0"])
+        );
+        assert!(next_n_addresses("255.255.255.254".into(), 3).is_err());
+    }
+
+    #[test]
+    fn is_host_route_for_full_length_prefix() {
+        assert!(is_host_route("10.0.0.1/32".into()).unwrap());
+        assert!(!is_host_route("10.0.0.0/24".into()).unwrap());
+        assert!(is_host_route("::1/128".into()).unwrap());
+    }
+
+    #[test]
+    fn mac_to_link_local_builds_eui64_address() {
+        assert_eq!(
+            mac_to_link_local("00:12:34:56:78:90".into()).unwrap(),
+            "fe80::212:34ff:fe56:7890"
+        );
+        assert_eq!(
+            mac_to_link_local("02-1A-2B-3C-4D-5E".into()).unwrap(),
+            "fe80::1a:2bff:fe3c:4d5e"
+        );
+        assert!(mac_to_link_local("+0:12:34:56:78:90".into()).is_err());
+        assert!(mac_to_link_local("00:12:34:56:78".into()).is_err());
+        assert!(mac_to_link_local("00:12-34:56:78:90".into()).is_err());
+    }
+
+    #[test]
+    fn range_to_cidr_stats_for_aligned_range() {
+        let stats = range_to_cidr_stats(strings(&["10.0.0.0-10.0.0.255"])).unwrap();
+        assert_eq!((stats.total_ranges, stats.total_cidrs), (1, 1));
+        assert_eq!(stats.total_addresses, "256");
+    }
+
+    #[test]
+    fn explain_unknown_gives_distinct_reasons() {
+        assert_eq!(
+            explain_unknown("10.0.0.256".into()).unwrap(),
+            "octet out of range"
+        );
+        assert_eq!(explain_unknown("".into()).unwrap(), "empty input");
+        assert_eq!(
+            explain_unknown("1:2:3:4:5:6:7:8:9".into()).unwrap(),
+            "too many colons"
+        );
+        assert!(explain_unknown("10.0.0.1".into()).is_err());
+    }
+
+    #[test]
+    fn prefix_match_binary_marks_first_difference() {
+        let (binary, matched) =
+            prefix_match_binary("10.0.0.0".into(), "10.0.128.0".into()).unwrap();
+        assert_eq!(matched, 16);
+        assert_eq!(binary, "0000101000000000|0000000000000000");
+        assert!(prefix_match_binary("10.0.0.0".into(), "::1".into()).is_err());
+    }
+
+    #[test]
+    fn filter_in_cidr_skips_other_families() {
+        assert_eq!(
+            filter_in_cidr(
+                strings(&["10.0.0.5", "10.0.1.5", "::1"]),
+                "10.0.0.0/24".into()
+            )
+            .unwrap(),
+            strings(&["10.0.0.5"])
+        );
+    }
+
+    #[test]
+    fn complement_ipv4_of_lower_half() {
+        assert_eq!(
+            complement_ipv4(strings(&["0.0.0.0/1"])).unwrap(),
+            strings(&["128.0.0.0/1"])
+        );
+        assert!(complement_ipv4(strings(&["::/1"])).is_err());
+    }
+
+    #[test]
+    fn density_by_slash24_counts_distinct_addresses() {
+        let ips: Vec<String> = (0..128)
+            .map(|i| format!("10.0.0.{}", i))
+            .chain(["10.0.0.5".to_string()])
+            .collect();
+        assert_eq!(
+            density_by_slash24(ips).unwrap(),
+            vec![("10.0.0.0/24".to_string(), 0.5)]
+        );
+    }
+
+    #[test]
+    fn classify_list_buckets_each_type() {
+        let lists = classify_list(strings(&[
+            "10.0.0.1",
+            "::1",
+            "10.0.0.0/8",
+            "10.0.0.1-10.0.0.9",
+            "nope",
+        ]))
+        .unwrap();
+        assert_eq!(lists.ipv4, strings(&["10.0.0.1"]));
+        assert_eq!(lists.ipv6, strings(&["::1"]));
+        assert_eq!(lists.cidrs, strings(&["10.0.0.0/8"]));
+        assert_eq!(lists.ranges, strings(&["10.0.0.1-10.0.0.9"]));
+        assert_eq!(lists.unknown, strings(&["nope"]));
+    }
+
+    #[test]
+    fn normalize_cidr_clears_host_bits() {
+        let normalized = normalize_cidr("10.0.0.5/24".into()).unwrap();
+        assert_eq!(normalized.input, "10.0.0.5/24");
+        assert_eq!(normalized.canonical, "10.0.0.0/24");
+        assert!(normalized.host_bits_set);
+        assert!(!normalize_cidr("10.0.0.0/24".into()).unwrap().host_bits_set);
+    }
+
+    #[test]
+    fn hex_ipv4_translates_and_rejects_sign() {
+        let result = translate_ip("0xC0A80001".into()).unwrap();
+        assert_eq!(result.com_address, "192.168.0.1");
+        assert_eq!(result.ex_address, "0xC0A80001");
+        assert!(matches!(
+            IpTranslationType::from_str("0x+1"),
+            IpTranslationType::UnknownIp
+        ));
+    }
+
+    #[test]
+    fn join_i64_pair_round_trips_split() {
+        let (high, low) = translate_ip("2001:db8::1".into())
+            .unwrap()
+            .high_low_64_bit_signed_number
+            .unwrap();
+        assert_eq!(
+            join_i64_pair(high, low, "ipv6".into()).unwrap(),
+            "2001:db8::1"
+        );
+        assert!(join_i64_pair(1, 0, "ipv4".into()).is_err());
+    }
+
+    #[test]
+    fn binary_ipv4_requires_dots_or_prefix() {
+        // 不带前缀的 32 位 0/1 串仍是十进制整数
+        assert!(matches!(
+            IpTranslationType::from_str("10000000000000000000000000000000"),
+            IpTranslationType::Ipv6Num
+        ));
+        for input in [
+            "11000000.10101000.00000001.00000001",
+            "0b11000000101010000000000100000001",
+        ] {
+            assert!(matches!(
+                IpTranslationType::from_str(input),
+                IpTranslationType::Ipv4
+            ));
+            assert_eq!(
+                translate_ip(input.into()).unwrap().com_address,
+                "192.168.1.1"
+            );
+        }
+        assert!(ip_in_cidr(
+            "11000000.10101000.00000001.00000001".into(),
+            "192.168.1.0/24".into()
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn ipv6_allocation_counts_for_slash32() {
+        let counts = ipv6_allocation_counts("2001:db8::/32".into()).unwrap();
+        assert_eq!(counts.slash48s, "65536");
+        assert_eq!(counts.slash64s, "4294967296");
+        // 前缀比 /48 更长时留空
+        assert_eq!(
+            ipv6_allocation_counts("2001:db8::/52".into())
+                .unwrap()
+                .slash48s,
+            ""
+        );
+    }
+
+    #[test]
+    fn subnet_one_liner_for_slash24() {
+        let line = subnet_one_liner("10.0.0.0/24".into()).unwrap();
+        assert!(line.contains("mask 255.255.255.0"));
+        assert!(line.contains("254 hosts"));
+        assert!(line.contains("bc 10.0.0.255"));
+    }
+
+    #[test]
+    fn parse_start_plus_size_expands_and_checks_overflow() {
+        assert_eq!(
+            parse_start_plus_size("10.0.0.0+256".into()).unwrap(),
+            "10.0.0.0-10.0.0.255"
+        );
+        assert!(parse_start_plus_size("255.255.255.0+257".into()).is_err());
+    }
+
+    #[test]
+    fn cidrs_bounding_range_spans_all_blocks() {
+        assert_eq!(
+            cidrs_bounding_range(strings(&["10.0.0.0/25", "10.0.1.0/25"])).unwrap(),
+            "10.0.0.0 - 10.0.1.127"
+        );
+        assert!(cidrs_bounding_range(strings(&["10.0.0.0/8", "::/0"])).is_err());
+    }
+
+    #[test]
+    fn enumerate_subnets_page_rejects_oversized_limit() {
+        assert!(enumerate_subnets_page("::/0".into(), 128, 0, u64::MAX).is_err());
+        assert!(enumerate_subnets_page("::/0".into(), 128, 0, MAX_SUBNET_PAGE + 1).is_err());
+        assert_eq!(
+            enumerate_subnets_page("::/0".into(), 128, 0, MAX_SUBNET_PAGE)
+                .unwrap()
+                .len(),
+            MAX_SUBNET_PAGE as usize
+        );
+    }
+
+    #[test]
+    fn split_subnet_into_equal_parts() {
+        assert_eq!(
+            split_subnet("10.0.0.0/24".into(), 4).unwrap(),
+            strings(&[
+                "10.0.0.0/26",
+                "10.0.0.64/26",
+                "10.0.0.128/26",
+                "10.0.0.192/26"
+            ])
+        );
+        assert!(split_subnet("10.0.0.0/24".into(), 3)
+            .unwrap_err()
+            .contains("2 的幂"));
+        assert!(split_subnet("10.0.0.0/31".into(), 4).is_err());
+        assert!(split_subnet("::/0".into(), 1 << 63)
+            .unwrap_err()
+            .contains("超出上限"));
+    }
+
+    #[test]
+    fn leading_zero_octets_are_rejected_everywhere() {
+        let err = translate_ip("010.0.0.1".into()).unwrap_err();
+        assert!(
+            err.contains("前导零") && err.contains("'10.0.0.1'"),
+            "{}",
+            err
+        );
+        let err = translate_ip("010.0.0.1-10.0.0.5".into()).unwrap_err();
+        assert!(err.contains("'10.0.0.1-10.0.0.5'"), "{}", err);
+        assert!(translate_ip("10.0.0.1-010".into())
+            .unwrap_err()
+            .contains("前导零"));
+        assert!(parse_range("10.0.0.1-010").is_err());
+        assert!(translate_ip_shorthand("010.1".into())
+            .unwrap_err()
+            .contains("前导零"));
+        assert_eq!(
+            translate_ip("10.0.0.1-10".into()).unwrap().com_address,
+            "10.0.0.1 - 10.0.0.10"
+        );
+    }
+
+    #[test]
+    fn translate_ip_shorthand_expands_inet_aton_forms() {
+        let result = translate_ip_shorthand("10.1".into()).unwrap();
+        assert_eq!(result.com_address, "10.0.0.1");
+        assert_eq!(result.ex_address, "10.1");
+        assert_eq!(
+            translate_ip_shorthand("127.1".into()).unwrap().com_address,
+            "127.0.0.1"
+        );
+        assert_eq!(
+            translate_ip_shorthand("192.168.1".into())
+                .unwrap()
+                .com_address,
+            "192.168.0.1"
+        );
+        // 完整写法不受影响
+        assert_eq!(
+            translate_ip_shorthand("10.0.0.1".into())
+                .unwrap()
+                .com_address,
+            "10.0.0.1"
+        );
+        assert!(translate_ip_shorthand("10.16777216".into())
+            .unwrap_err()
+            .contains("简写规则"));
+        assert!(translate_ip_shorthand("256.1".into()).is_err());
+        assert!(translate_ip_shorthand("10.a".into()).is_err());
+        assert!(translate_ip_shorthand("10.01".into())
+            .unwrap_err()
+            .contains("前导零"));
+    }
+
+    #[test]
+    fn translate_ip_reports_usable_hosts() {
+        let result = translate_ip("192.168.1.0/24".into()).unwrap();
+        assert_eq!(result.usable_host_count, "254");
+        assert_eq!(result.first_usable_host, "192.168.1.1");
+        assert_eq!(result.last_usable_host, "192.168.1.254");
+    }
+
+    #[test]
+    fn translate_ip_handles_point_to_point_and_host_routes() {
+        // /31 按 RFC 3021 两个地址都可用，没有广播地址
+        let result = translate_ip("10.0.0.0/31".into()).unwrap();
+        assert!(result.is_point_to_point);
+        assert_eq!(result.broadcast_address, "");
+        assert_eq!(result.usable_host_count, "2");
+        assert_eq!(result.first_usable_host, "10.0.0.0");
+        assert_eq!(result.last_usable_host, "10.0.0.1");
+
+        let result = translate_ip("10.0.0.7/32".into()).unwrap();
+        assert!(!result.is_point_to_point);
+        assert_eq!(result.net_work_address, "10.0.0.7");
+        assert_eq!(result.broadcast_address, "");
+        assert_eq!(result.usable_host_count, "1");
+        assert_eq!(result.first_usable_host, "10.0.0.7");
+        assert_eq!(result.last_usable_host, "10.0.0.7");
+    }
+
+    #[test]
+    fn translate_ip_reports_total_addresses() {
+        assert_eq!(
+            translate_ip("0.0.0.0/0".into()).unwrap().total_addresses,
+            "4294967296"
+        );
+        assert_eq!(
+            translate_ip("2001:db8::/64".into())
+                .unwrap()
+                .total_addresses,
+            "18446744073709551616"
+        );
+        assert_eq!(
+            translate_ip("::/0".into()).unwrap().total_addresses,
+            "340282366920938463463374607431768211456"
+        );
+    }
+
+    #[test]
+    fn translate_ip_reports_ipv4_class() {
+        assert_eq!(translate_ip("10.0.0.1".into()).unwrap().ipv4_class, "A");
+        assert_eq!(translate_ip("172.16.0.1".into()).unwrap().ipv4_class, "B");
+        assert_eq!(translate_ip("224.0.0.1".into()).unwrap().ipv4_class, "D");
+        assert_eq!(translate_ip("4294967295".into()).unwrap().ipv4_class, "E");
+        assert_eq!(translate_ip("::1".into()).unwrap().ipv4_class, "");
+    }
+
+    #[test]
+    fn translate_ip_reports_ipv4_scope() {
+        for (ip, scope) in [
+            ("192.168.1.1", "private"),
+            ("127.0.0.1", "loopback"),
+            ("169.254.1.1", "link-local"),
+            ("224.0.0.1", "multicast"),
+            ("192.0.2.5", "documentation"),
+            ("8.8.8.8", "global"),
+        ] {
+            assert_eq!(translate_ip(ip.into()).unwrap().scope, scope, "{}", ip);
+        }
+    }
+
+    #[test]
+    fn aggregate_with_report_rejects_mixed_families() {
+        let err = aggregate_with_report(strings(&["10.0.0.0/25", "::/0"])).unwrap_err();
+        assert!(err.contains("地址族不一致"), "{}", err);
+        assert!(aggregate_with_report(Vec::new()).is_err());
+    }
+
+    #[test]
+    fn translate_ip_keeps_link_local_zone_id() {
+        let result = translate_ip("fe80::1%eth0".into()).unwrap();
+        assert_eq!(result.zone_id, "eth0");
+        assert_eq!(result.com_address, "fe80::1");
+        // 区域标识只对链路本地地址有意义
+        assert!(translate_ip("2001:db8::1%eth0".into()).is_err());
+        assert!(translate_ip("fe80::1%".into()).is_err());
+    }
+
+    #[test]
+    fn translate_ip_accepts_range_with_equal_ends() {
+        assert!(matches!(
+            IpTranslationType::from_str("10.0.0.5-10.0.0.5"),
+            IpTranslationType::Ipv4Range
+        ));
+        assert_eq!(
+            translate_ip("10.0.0.5-10.0.0.5".into())
+                .unwrap()
+                .total_addresses,
+            "1"
+        );
+        assert_eq!(translate_ip("::1-::1".into()).unwrap().total_addresses, "1");
+    }
+
+    #[test]
+    fn translate_ip_builds_reverse_dns_names() {
+        assert_eq!(
+            translate_ip("192.0.2.5".into()).unwrap().reverse_dns,
+            "5.2.0.192.in-addr.arpa"
+        );
+        assert_eq!(
+            translate_ip("2001:db8::1".into()).unwrap().reverse_dns,
+            "1.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.8.b.d.0.1.0.0.2.ip6.arpa"
+        );
+    }
+
+    #[test]
+    fn translate_ip_recovers_eui64_mac() {
+        assert_eq!(
+            translate_ip("fe80::0212:34ff:fe56:7890".into())
+                .unwrap()
+                .embedded_mac,
+            "00:12:34:56:78:90"
+        );
+        // 没有 ff:fe 标记的接口标识不是 EUI-64
+        assert_eq!(translate_ip("fe80::1".into()).unwrap().embedded_mac, "");
+    }
+
+    #[test]
+    fn range_to_cidrs_emits_minimal_blocks() {
+        assert_eq!(
+            range_to_cidrs("192.168.1.5-192.168.1.20".into()).unwrap(),
+            strings(&[
+                "192.168.1.5/32",
+                "192.168.1.6/31",
+                "192.168.1.8/29",
+                "192.168.1.16/30",
+                "192.168.1.20/32"
+            ])
+        );
+        assert_eq!(
+            range_to_cidrs("2001:db8::-2001:db8::ff".into()).unwrap(),
+            strings(&["2001:db8::/120"])
+        );
+        assert!(range_to_cidrs("10.0.0.9-10.0.0.1".into()).is_err());
+        assert!(range_to_cidrs("10.0.0.1-::1".into()).is_err());
+        assert!(range_to_cidrs("10.0.0.1".into()).is_err());
+    }
+
+    #[test]
+    fn cidr_to_range_returns_first_and_last() {
+        assert_eq!(
+            cidr_to_range("10.0.0.0/24".into()).unwrap(),
+            ("10.0.0.0".to_string(), "10.0.0.255".to_string())
+        );
+        assert_eq!(
+            cidr_to_range("2001:db8::/32".into()).unwrap(),
+            (
+                "2001:db8::".to_string(),
+                "2001:db8:ffff:ffff:ffff:ffff:ffff:ffff".to_string()
+            )
+        );
+        assert!(cidr_to_range("10.0.0.0/33".into()).is_err());
+        assert!(cidr_to_range("10.0.0.0".into()).is_err());
+    }
+
+    #[test]
+    fn aggregate_cidrs_merges_adjacent_and_overlapping() {
+        assert_eq!(
+            aggregate_cidrs(strings(&["10.0.0.128/25", "10.0.0.0/25"])).unwrap(),
+            strings(&["10.0.0.0/24"])
+        );
+        assert_eq!(
+            aggregate_cidrs(strings(&["10.0.0.0/24", "10.0.0.64/26", "10.0.2.0/24"])).unwrap(),
+            strings(&["10.0.0.0/24", "10.0.2.0/24"])
+        );
+        assert!(aggregate_cidrs(strings(&["10.0.0.0/24", "::/64"])).is_err());
+        assert!(aggregate_cidrs(strings(&["10.0.0.0/24", "junk"])).is_err());
+    }
+
+    #[test]
+    fn ip_in_cidr_checks_membership() {
+        assert!(ip_in_cidr("10.0.0.5".into(), "10.0.0.0/24".into()).unwrap());
+        assert!(!ip_in_cidr("10.0.1.5".into(), "10.0.0.0/24".into()).unwrap());
+        assert!(ip_in_cidr("2001:db8::1".into(), "2001:db8::/32".into()).unwrap());
+        assert!(ip_in_cidr("10.0.0.5".into(), "::/0".into()).is_err());
+        assert!(ip_in_cidr("junk".into(), "10.0.0.0/24".into()).is_err());
+    }
+
+    #[test]
+    fn cidrs_overlap_compares_bounds() {
+        assert!(cidrs_overlap("10.0.0.0/24".into(), "10.0.0.128/25".into()).unwrap());
+        assert!(!cidrs_overlap("10.0.0.0/25".into(), "10.0.0.128/25".into()).unwrap());
+        assert!(cidrs_overlap("2001:db8::/32".into(), "::/0".into()).unwrap());
+        assert!(cidrs_overlap("10.0.0.0/8".into(), "::/0".into()).is_err());
+        assert!(cidrs_overlap("10.0.0.0/8".into(), "10.0.0.0".into()).is_err());
+    }
+
+    #[test]
+    fn translate_ip_batch_keeps_per_item_errors() {
+        let batch = translate_ip_batch(strings(&["10.0.0.1", "garbage", "::1"]));
+        assert_eq!(batch.len(), 3);
+        assert_eq!(batch[0].result.as_ref().unwrap().com_address, "10.0.0.1");
+        assert_eq!(batch[1].input, "garbage");
+        assert!(batch[1].result.is_none() && batch[1].error.is_some());
+        assert!(batch[2].error.is_none());
+    }
+
+    #[test]
+    fn start_plus_size_rejects_signed_size_and_translates() {
+        assert!(parse_start_plus_size("10.0.0.0++5".into()).is_err());
+        assert!(parse_start_plus_size("10.0.0.0+-5".into()).is_err());
+        assert!(matches!(
+            IpTranslationType::from_str("10.0.0.0+256"),
+            IpTranslationType::StartPlusSize
+        ));
+        assert!(matches!(
+            IpTranslationType::from_str("10.0.0.0++5"),
+            IpTranslationType::UnknownIp
+        ));
+        let result = translate_ip("10.0.0.0+256".into()).unwrap();
+        assert_eq!(result.com_address, "10.0.0.0 - 10.0.0.255");
+        assert_eq!(result.ex_address, "10.0.0.0+256");
+        assert_eq!(result.total_addresses, "256");
+        assert!(translate_ip("255.255.255.0+257".into()).is_err());
+        assert_eq!(
+            classify_list(strings(&["10.0.0.0+256"])).unwrap().ranges,
+            strings(&["10.0.0.0+256"])
+        );
+    }
+
+    #[test]
+    fn translate_ip_reports_wildcard_mask() {
+        assert_eq!(
+            translate_ip("10.0.0.0/24".into()).unwrap().wildcard_mask,
+            "0.0.0.255"
+        );
+        assert_eq!(
+            translate_ip("10.0.0.1/32".into()).unwrap().wildcard_mask,
+            "0.0.0.0"
+        );
+        assert_eq!(translate_ip("::/64".into()).unwrap().wildcard_mask, "");
+    }
+}
@@ -6,7 +6,91 @@ pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_clipboard_manager::init())
         .invoke_handler(tauri::generate_handler![commands::greet])
-        .invoke_handler(tauri::generate_handler![ip_commands::translate_ip])
+        .invoke_handler(tauri::generate_handler![
+            ip_commands::translate_ip,
+            ip_commands::parse_hex_octets,
+            ip_commands::cidr_symmetric_difference,
+            ip_commands::hilbert_xy,
+            ip_commands::well_known,
+            ip_commands::range_to_cidrs_limited,
+            ip_commands::octets,
+            ip_commands::range_as_single_cidr,
+            ip_commands::host_position_fraction,
+            ip_commands::address_before_block,
+            ip_commands::range_cidr_sizes,
+            ip_commands::network_of,
+            ip_commands::prefix_table,
+            ip_commands::suggest_fix,
+            ip_commands::touched_slash24s,
+            ip_commands::boundary_roles,
+            ip_commands::verify_roundtrip,
+            ip_commands::ipv6_nibbles,
+            ip_commands::is_cgnat,
+            ip_commands::ula_breakdown,
+            ip_commands::separating_prefix,
+            ip_commands::halve,
+            ip_commands::is_cidr,
+            ip_commands::union_size,
+            ip_commands::ipv6_host_fraction,
+            ip_commands::wildcard_matches,
+            ip_commands::prefix_list,
+            ip_commands::multicast_scope,
+            ip_commands::multicast_lookup,
+            ip_commands::aggregation_savings,
+            ip_commands::canonicalize_blob,
+            ip_commands::blackhole_slash24s,
+            ip_commands::to_u64_pair,
+            ip_commands::from_u64_pair,
+            ip_commands::would_overlap,
+            ip_commands::address_at_percent,
+            ip_commands::from_octets,
+            ip_commands::from_segments,
+            ip_commands::nearest_prefix_label,
+            ip_commands::rfc2317_zone,
+            ip_commands::ipv6_compression_map,
+            ip_commands::enumerate_subnets_page,
+            ip_commands::reflect_in_cidr,
+            ip_commands::single_aggregate,
+            ip_commands::evaluate_policy,
+            ip_commands::ipcalc_json,
+            ip_commands::shard_bucket,
+            ip_commands::translate_ip_fields,
+            ip_commands::range_to_cidrs_sorted,
+            ip_commands::cidr_representation_waste,
+            ip_commands::plan_fingerprint,
+            ip_commands::prefix_around_host,
+            ip_commands::isatap_extract,
+            ip_commands::isatap_build,
+            ip_commands::aggregate_with_report,
+            ip_commands::parity,
+            ip_commands::summarize_object_group,
+            ip_commands::usable_role_slash24,
+            ip_commands::cidr_exclude_many,
+            ip_commands::next_n_addresses,
+            ip_commands::is_host_route,
+            ip_commands::mac_to_link_local,
+            ip_commands::range_to_cidr_stats,
+            ip_commands::range_to_cidrs,
+            ip_commands::explain_unknown,
+            ip_commands::cidr_to_range,
+            ip_commands::prefix_match_binary,
+            ip_commands::split_subnet,
+            ip_commands::filter_in_cidr,
+            ip_commands::aggregate_cidrs,
+            ip_commands::complement_ipv4,
+            ip_commands::ip_in_cidr,
+            ip_commands::density_by_slash24,
+            ip_commands::cidrs_overlap,
+            ip_commands::classify_list,
+            ip_commands::translate_ip_batch,
+            ip_commands::normalize_cidr,
+            ip_commands::join_i64_pair,
+            ip_commands::ipv6_allocation_counts,
+            ip_commands::translate_ip_shorthand,
+            ip_commands::subnet_one_liner,
+            ip_commands::parse_start_plus_size,
+            ip_commands::cidrs_bounding_range,
+        ])
         .setup(|app| {
             if cfg!(debug_assertions) {
                 app.handle().plugin(
@@ -31,6 +31,11 @@ export const IpFieldService = {
                 field: "subnetMask",
                 value: "",
             },
+            {
+                key: "反掩码（Wildcard）",
+                field: "wildcardMask",
+                value: "",
+            },
             {
                 key: "上一个地址",
                 field: "prevAddress",
@@ -100,6 +105,61 @@ export const IpFieldService = {
                 key: "广播地址（结束ip）二进制地址",
                 field: "broadcastAddressBinaryAddress",
                 value: "",
+            },
+            {
+                key: "区域标识（Zone ID）",
+                field: "zoneId",
+                value: "",
+            },
+            {
+                key: "地址范围",
+                field: "scope",
+                value: "",
+            },
+            {
+                key: "IPv4 地址分类",
+                field: "ipv4Class",
+                value: "",
+            },
+            {
+                key: "地址总数",
+                field: "totalAddresses",
+                value: "",
+            },
+            {
+                key: "可用主机数",
+                field: "usableHostCount",
+                value: "",
+            },
+            {
+                key: "第一个可用主机",
+                field: "firstUsableHost",
+                value: "",
+            },
+            {
+                key: "最后一个可用主机",
+                field: "lastUsableHost",
+                value: "",
+            },
+            {
+                key: "点对点链路（/31）",
+                field: "isPointToPoint",
+                value: "",
+            },
+            {
+                key: "反向解析（PTR）",
+                field: "reverseDns",
+                value: "",
+            },
+            {
+                key: "内嵌 MAC（EUI-64）",
+                field: "embeddedMac",
+                value: "",
+            },
+            {
+                key: "输入使用嵌入 IPv4 写法",
+                field: "inputUsedMixedNotation",
+                value: "",
             }
         ];
     },